@@ -0,0 +1,87 @@
+use base4::Base4Int;
+use criterion::{BatchSize, BenchmarkId, Criterion, black_box, criterion_group, criterion_main};
+
+const DIGITS: usize = 1_000_000;
+
+fn digits(len: usize) -> Vec<u8> {
+    (0..len).map(|i| (i * 7 % 4) as u8).collect()
+}
+
+fn push(c: &mut Criterion) {
+    let ints = digits(DIGITS);
+    let mut group = c.benchmark_group("push");
+
+    group.bench_function(BenchmarkId::new("new", DIGITS), |b| {
+        b.iter(|| {
+            let mut big_int = Base4Int::new();
+            ints.iter().for_each(|int| big_int.push(*int));
+            black_box(big_int)
+        })
+    });
+    group.bench_function(BenchmarkId::new("with_capacity", DIGITS), |b| {
+        b.iter(|| {
+            let mut big_int = Base4Int::with_capacity(DIGITS);
+            ints.iter().for_each(|int| big_int.push(*int));
+            black_box(big_int)
+        })
+    });
+
+    group.finish();
+}
+
+fn peek_all(c: &mut Criterion) {
+    let mut big_int = Base4Int::with_capacity(DIGITS);
+    big_int.push_all(&digits(DIGITS));
+    let mut group = c.benchmark_group("peek_all");
+
+    group.bench_function(BenchmarkId::new("per_digit", DIGITS), |b| {
+        b.iter(|| {
+            big_int
+                .blocks()
+                .flat_map(|block| (0..block.len()).map(|index| block.peek_at::<u8>(index)))
+                .collect::<Vec<_>>()
+        })
+    });
+    group.bench_function(BenchmarkId::new("bulk", DIGITS), |b| {
+        b.iter(|| big_int.peek_all::<u8>())
+    });
+
+    group.finish();
+}
+
+fn pop_all(c: &mut Criterion) {
+    let mut big_int = Base4Int::with_capacity(DIGITS);
+    big_int.push_all(&digits(DIGITS));
+    let mut group = c.benchmark_group("pop_all");
+
+    group.bench_function(BenchmarkId::new("per_block", DIGITS), |b| {
+        b.iter_batched(
+            || big_int.clone().into_blocks(),
+            |blocks| {
+                let mut ints = Vec::with_capacity(DIGITS);
+                for mut block in blocks {
+                    let mut digits = Vec::new();
+                    while let Some(digit) = block.pop() {
+                        digits.push(digit);
+                    }
+                    digits.reverse();
+                    ints.extend(digits);
+                }
+                ints
+            },
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function(BenchmarkId::new("drain", DIGITS), |b| {
+        b.iter_batched(
+            || big_int.clone(),
+            |mut big_int| big_int.pop_all::<u8>(),
+            BatchSize::LargeInput,
+        )
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, push, peek_all, pop_all);
+criterion_main!(benches);
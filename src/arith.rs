@@ -0,0 +1,147 @@
+use alloc::vec::Vec;
+
+use crate::Base4Int;
+
+impl Base4Int {
+    /// Converts the digits, read as a base4 number with the first
+    /// digit being the most significant, into their reflected
+    /// quaternary Gray code.
+    ///
+    /// Consecutive numbers of the same width map onto Gray codes
+    /// which differ in exactly one digit.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[1_u8, 0]);
+    ///
+    /// assert!(big_int.to_gray().peek_all::<u8>() == vec![1, 3]);
+    /// assert!(big_int.to_gray().from_gray().peek_all::<u8>() == vec![1, 0]);
+    /// ```
+    pub fn to_gray(&self) -> Base4Int {
+        let mut reflected = false;
+        self.map_reflected(|digit| {
+            let gray = if reflected { 3 - digit } else { digit };
+            reflected ^= gray & 1 == 1;
+            gray
+        })
+    }
+
+    /// Converts a reflected quaternary Gray code back into plain
+    /// base4 digits, inverting [Base4Int::to_gray].
+    pub fn from_gray(&self) -> Base4Int {
+        let mut reflected = false;
+        self.map_reflected(|gray| {
+            let digit = if reflected { 3 - gray } else { gray };
+            reflected ^= gray & 1 == 1;
+            digit
+        })
+    }
+
+    /// Multiplies the base4 number by a small `factor`, returning
+    /// `None` if the product needs more than `max_digits` digits.
+    ///
+    /// The product is normalized without leading zeros, a zero product
+    /// being a single `0` digit.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[3_u8, 3]); // 15
+    ///
+    /// let product = big_int.checked_mul_small(2, 3).unwrap();
+    ///
+    /// assert!(product.peek_all::<u8>() == vec![1, 3, 2]); // 30
+    /// assert!(big_int.checked_mul_small(2, 2).is_none());
+    /// ```
+    pub fn checked_mul_small(&self, factor: u8, max_digits: usize) -> Option<Base4Int> {
+        let mut product = Vec::with_capacity(self.total_len() + 4);
+        let mut carry = 0_u32;
+
+        for digit in self.peek_all::<u32>().into_iter().rev() {
+            let value = digit * factor as u32 + carry;
+            product.push((value % 4) as u8);
+            carry = value / 4;
+        }
+
+        while carry > 0 {
+            product.push((carry % 4) as u8);
+            carry /= 4;
+        }
+
+        product.reverse();
+        let product = normalized(&product);
+
+        (product.total_len() <= max_digits).then_some(product)
+    }
+
+    /// Subtracts `other` from `self`, both read as base4 numbers with
+    /// the first digit being the most significant.
+    ///
+    /// Borrows propagate from the least significant digit and the
+    /// difference is normalized like [Base4Int::checked_mul_small].
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut a = Base4Int::new();
+    /// let mut b = Base4Int::new();
+    ///
+    /// a.push_all(&[1_u8, 0, 0]); // 16
+    /// b.push_all(&[0_u8, 0, 3]); // 3
+    ///
+    /// assert!(a.checked_sub(&b).unwrap().peek_all::<u8>() == vec![3, 1]); // 13
+    /// assert!(b.checked_sub(&a).is_none());
+    /// ```
+    /// Returns `None` if `other` is numerically greater than `self`.
+    pub fn checked_sub(&self, other: &Base4Int) -> Option<Base4Int> {
+        let lhs = self.peek_all::<i16>();
+        let rhs = other.peek_all::<i16>();
+
+        let mut difference = Vec::with_capacity(lhs.len().max(rhs.len()));
+        let mut borrow = 0;
+
+        for index in 0..lhs.len().max(rhs.len()) {
+            let a = lhs.len().checked_sub(index + 1).map_or(0, |i| lhs[i]);
+            let b = rhs.len().checked_sub(index + 1).map_or(0, |i| rhs[i]);
+
+            let mut digit = a - b - borrow;
+            borrow = (digit < 0) as i16;
+            if digit < 0 {
+                digit += 4;
+            }
+            difference.push(digit as u8);
+        }
+
+        if borrow > 0 {
+            return None;
+        }
+
+        difference.reverse();
+        Some(normalized(&difference))
+    }
+
+    /// Maps every digit in order into a new `Base4Int`.
+    fn map_reflected<F>(&self, mut f: F) -> Base4Int
+    where
+        F: FnMut(u8) -> u8,
+    {
+        let mut mapped = Base4Int::new();
+        self.digits().for_each(|digit| mapped.push(f(digit)));
+        mapped
+    }
+}
+
+/// Packs the digits of a number, most significant first, skipping its
+/// leading zeros. Zero is kept as a single `0` digit.
+fn normalized(digits: &[u8]) -> Base4Int {
+    match digits.iter().position(|digit| *digit != 0) {
+        Some(start) => Base4Int::build_from_chunks(&[&digits[start..]]),
+        None => Base4Int::build_from_chunks(&[&[0]]),
+    }
+}
@@ -0,0 +1,335 @@
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::io::{self, Read, Write};
+
+use crate::{Base4, Base4Error, Base4Int};
+
+/// Lookup table for the reflected CRC-32 (IEEE 802.3) polynomial.
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0_u32; 256];
+    let mut n = 0;
+    while n < 256 {
+        let mut crc = n as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[n] = crc;
+        n += 1;
+    }
+    table
+};
+
+impl Base4Int {
+    /// Computes the CRC-32 (IEEE) checksum of the bit-packed digit
+    /// stream, where every byte holds 4 digits, first digit in the
+    /// most significant bits.
+    ///
+    /// The checksum only depends on the digit sequence, so two
+    /// integers holding the same digits always share the same CRC.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut a = Base4Int::new();
+    /// let mut b = Base4Int::new();
+    ///
+    /// a.push_all(&[0_u8, 1, 2, 3]);
+    /// b.push_all(&[0_u8, 1, 2, 3]);
+    ///
+    /// assert!(a.crc32() == b.crc32());
+    /// ```
+    pub fn crc32(&self) -> u32 {
+        let mut bytes = Vec::with_capacity(self.byte_len());
+        self.write_bitpacked(&mut bytes);
+
+        !bytes.iter().fold(!0_u32, |crc, byte| {
+            CRC32_TABLE[((crc ^ *byte as u32) & 0xFF) as usize] ^ (crc >> 8)
+        })
+    }
+
+    /// Writes the canonical 2-bits-per-digit representation into
+    /// `out`, first digit in the most significant bits of the first
+    /// byte.
+    ///
+    /// `out` is cleared first so its allocation can be reused across
+    /// calls; it only grows when it cannot fit the packed bytes.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[0_u8, 1, 2, 3, 2]);
+    ///
+    /// let mut out = vec![0xFF; 8];
+    /// big_int.to_bitpacked_into(&mut out);
+    ///
+    /// assert!(out == vec![0b00_01_10_11, 0b10_00_00_00]);
+    /// ```
+    pub fn to_bitpacked_into(&self, out: &mut Vec<u8>) {
+        out.clear();
+        out.reserve(self.byte_len());
+        self.write_bitpacked(out);
+    }
+
+    /// Serializes the digits into a compact byte buffer, made of the
+    /// digit count as a little-endian `u64` header followed by the
+    /// digits packed 4 per byte, first digit in the most significant
+    /// bits.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[0_u8, 1, 2, 3, 2]);
+    ///
+    /// let bytes = big_int.to_bytes();
+    /// assert!(bytes == vec![5, 0, 0, 0, 0, 0, 0, 0, 0b00_01_10_11, 0b10_00_00_00]);
+    ///
+    /// let decoded = Base4Int::from_bytes(&bytes).unwrap();
+    /// assert!(decoded.peek_all::<u8>() == vec![0, 1, 2, 3, 2]);
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(8 + self.byte_len());
+        bytes.extend_from_slice(&(self.total_len() as u64).to_le_bytes());
+        self.write_bitpacked(&mut bytes);
+        bytes
+    }
+
+    /// Reconstructs a `Base4Int` from the bytes produced by
+    /// [Base4Int::to_bytes].
+    ///
+    /// Only the digits counted by the header are decoded, so the
+    /// padding bits of the last byte never turn into digits.
+    ///
+    /// Returns [Base4Error::BufferTooShort] if `bytes` cannot hold the
+    /// header or the digits it announces, and [Base4Error::Malformed]
+    /// if bytes are left over after them.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Base4Int, Base4Error> {
+        let Some((header, packed)) = bytes.split_first_chunk::<8>() else {
+            return Err(Base4Error::BufferTooShort {
+                required: 8,
+                actual: bytes.len(),
+            });
+        };
+
+        let count = u64::from_le_bytes(*header) as usize;
+        let required = 8 + count.div_ceil(4);
+        if bytes.len() < required {
+            return Err(Base4Error::BufferTooShort {
+                required,
+                actual: bytes.len(),
+            });
+        }
+        if bytes.len() > required {
+            return Err(Base4Error::Malformed { offset: required });
+        }
+
+        let mut int = Base4Int::new();
+        int.fill_from_slice_packed(packed, count)?;
+        Ok(int)
+    }
+
+    /// Clears `self` and loads `count` digits from a 2-bits-per-digit
+    /// packed byte slice, first digit in the most significant bits.
+    ///
+    /// The already allocated block storage is reused. Bits of the last
+    /// byte beyond `count` digits are ignored.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.fill_from_slice_packed(&[0b00_01_10_11, 0b10_00_00_00], 5).unwrap();
+    ///
+    /// assert!(big_int.peek_all::<u8>() == vec![0, 1, 2, 3, 2]);
+    /// ```
+    ///
+    /// Returns [Base4Error::BufferTooShort] if `packed` holds fewer
+    /// than `count` digits, leaving `self` untouched.
+    pub fn fill_from_slice_packed(
+        &mut self,
+        packed: &[u8],
+        count: usize,
+    ) -> Result<(), Base4Error> {
+        let required = count.div_ceil(4);
+        if packed.len() < required {
+            return Err(Base4Error::BufferTooShort {
+                required,
+                actual: packed.len(),
+            });
+        }
+
+        self.clear();
+
+        let mut remaining = count;
+        for chunk in packed[..required].chunks(16) {
+            let size = remaining.min(64);
+            let mut bytes = [0_u8; 16];
+            bytes[..chunk.len()].copy_from_slice(chunk);
+
+            let packed = u128::from_be_bytes(bytes) >> (2 * (64 - size));
+            self.0.push_back(Base4 { size, packed });
+            remaining -= size;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the digits as ASCII characters `b'0'..=b'3'`, ready to
+    /// be written to a text stream.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[0_u8, 1, 2, 3]);
+    ///
+    /// assert!(big_int.as_ascii_digits() == b"0123");
+    /// ```
+    pub fn as_ascii_digits(&self) -> Vec<u8> {
+        self.digits().map(|digit| b'0' + digit).collect()
+    }
+
+    /// Parses ASCII characters `b'0'..=b'3'` back into a `Base4Int`.
+    ///
+    /// Returns [Base4Error::InvalidDigit] for the first byte outside
+    /// of that range.
+    pub fn from_ascii_digits(ascii: &[u8]) -> Result<Base4Int, Base4Error> {
+        let mut int = Base4Int::new();
+        for (position, byte) in ascii.iter().enumerate() {
+            match byte {
+                b'0'..=b'3' => int.push(byte - b'0'),
+                _ => {
+                    return Err(Base4Error::InvalidDigit {
+                        ch: *byte as char,
+                        position,
+                    });
+                }
+            }
+        }
+
+        Ok(int)
+    }
+
+    /// Writes every integer to `w` as a record made of its digit count,
+    /// as a little-endian `u64`, followed by its bit-packed digits.
+    ///
+    /// Records are written one at a time, so the integers never need
+    /// to be held in memory together.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[0_u8, 1, 2, 3]);
+    ///
+    /// let mut out = Vec::new();
+    /// Base4Int::serialize_stream([big_int].into_iter(), &mut out).unwrap();
+    ///
+    /// let mut reader = out.as_slice();
+    /// let mut records = Base4Int::deserialize_stream(&mut reader);
+    ///
+    /// assert!(records.next().unwrap().unwrap().peek_all::<u8>() == vec![0, 1, 2, 3]);
+    /// assert!(records.next().is_none());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn serialize_stream<W: Write>(
+        ints: impl Iterator<Item = Base4Int>,
+        w: &mut W,
+    ) -> io::Result<()> {
+        let mut packed = Vec::new();
+        for int in ints {
+            write_record(&int, &mut packed, w)?;
+        }
+
+        Ok(())
+    }
+
+    /// Lazily reads back the records written by [Base4Int::serialize_stream].
+    ///
+    /// The iterator ends at a clean end of input, or right after
+    /// yielding the first error, e.g. on a truncated record.
+    #[cfg(feature = "std")]
+    pub fn deserialize_stream<R: Read>(r: &mut R) -> impl Iterator<Item = io::Result<Base4Int>> {
+        let mut done = false;
+        std::iter::from_fn(move || {
+            if done {
+                return None;
+            }
+
+            let record = read_record(r);
+            done = !matches!(record, Ok(Some(_)));
+            record.transpose()
+        })
+    }
+
+    /// Appends the canonical 2-bits-per-digit representation of
+    /// all the digits to `out`.
+    ///
+    /// Every block except the last one is full, so each of them maps
+    /// onto exactly 16 bytes; the last block is left-aligned and only
+    /// its used bytes are written.
+    pub(crate) fn write_bitpacked(&self, out: &mut Vec<u8>) {
+        for block in self.0.iter() {
+            let aligned = block.packed << (2 * (64 - block.size));
+            let used = block.byte_len();
+            out.extend_from_slice(&aligned.to_be_bytes()[..used]);
+        }
+    }
+}
+
+/// Writes `int` as a length-prefixed record, using `packed` as
+/// scratch space.
+#[cfg(feature = "std")]
+pub(crate) fn write_record<W: Write>(
+    int: &Base4Int,
+    packed: &mut Vec<u8>,
+    w: &mut W,
+) -> io::Result<()> {
+    int.to_bitpacked_into(packed);
+    w.write_all(&(int.total_len() as u64).to_le_bytes())?;
+    w.write_all(packed)
+}
+
+/// Reads a single length-prefixed record, returning `None` if the
+/// input ends right before it.
+#[cfg(feature = "std")]
+pub(crate) fn read_record<R: Read>(r: &mut R) -> io::Result<Option<Base4Int>> {
+    let mut header = [0_u8; 8];
+    let mut filled = 0;
+
+    while filled < header.len() {
+        match r.read(&mut header[filled..]) {
+            Ok(0) if filled == 0 => return Ok(None),
+            Ok(0) => return Err(io::ErrorKind::UnexpectedEof.into()),
+            Ok(read) => filled += read,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+            Err(err) => return Err(err),
+        }
+    }
+
+    let count = u64::from_le_bytes(header) as usize;
+    let required = count.div_ceil(4);
+
+    // Grow the buffer with the input rather than trusting the header.
+    let mut packed = Vec::new();
+    r.by_ref().take(required as u64).read_to_end(&mut packed)?;
+
+    let mut int = Base4Int::new();
+    int.fill_from_slice_packed(&packed, count)
+        .map_err(|_| io::Error::from(io::ErrorKind::UnexpectedEof))?;
+
+    Ok(Some(int))
+}
@@ -0,0 +1,269 @@
+use alloc::vec::Vec;
+use core::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+};
+
+use crate::{Base4, Base4Int};
+
+impl Base4Int {
+    /// Lists every position where `self` and `other` differ, as
+    /// `(index, self_digit, other_digit)` triples in index order.
+    ///
+    /// Equal-length integers share the same block layout, so blocks
+    /// with identical packed words are skipped without decoding.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut a = Base4Int::new();
+    /// let mut b = Base4Int::new();
+    ///
+    /// a.push_all(&[0_u8, 1, 2, 3]);
+    /// b.push_all(&[0_u8, 3, 2, 3]);
+    ///
+    /// assert!(a.diff(&b) == vec![(1, 1, 3)]);
+    /// ```
+    /// # Panics
+    ///
+    /// This method may panic if both integers differ in length.
+    pub fn diff(&self, other: &Base4Int) -> Vec<(usize, u8, u8)> {
+        assert!(
+            self.total_len() == other.total_len(),
+            "diff: length mismatch ({} != {})",
+            self.total_len(),
+            other.total_len()
+        );
+
+        let mut diffs = Vec::new();
+        for (block_idx, (lhs, rhs)) in self.0.iter().zip(other.0.iter()).enumerate() {
+            if lhs.packed == rhs.packed {
+                continue;
+            }
+
+            for index in 0..lhs.size {
+                let (a, b) = (lhs.peek_at::<u8>(index), rhs.peek_at::<u8>(index));
+                if a != b {
+                    diffs.push((block_idx * 64 + index, a, b));
+                }
+            }
+        }
+
+        diffs
+    }
+
+    /// Returns the sorted positions where `self` and `other` differ.
+    /// When lengths differ, every position past the shorter one counts
+    /// as differing.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut a = Base4Int::new();
+    /// let mut b = Base4Int::new();
+    ///
+    /// a.push_all(&[0_u8, 1, 2]);
+    /// b.push_all(&[0_u8, 2, 2, 3]);
+    ///
+    /// assert!(a.differing_positions(&b) == vec![1, 3]);
+    /// ```
+    pub fn differing_positions(&self, other: &Base4Int) -> Vec<usize> {
+        let (len, other_len) = (self.total_len(), other.total_len());
+
+        let mut positions: Vec<usize> = self
+            .digits()
+            .zip(other.digits())
+            .enumerate()
+            .filter(|(_, (a, b))| a != b)
+            .map(|(index, _)| index)
+            .collect();
+
+        positions.extend(len.min(other_len)..len.max(other_len));
+        positions
+    }
+
+    /// Sets each listed `(index, digit)` position, e.g. to rebuild a
+    /// variant from a reference and the positions where they differ.
+    ///
+    /// All the entries are validated before any digit is written.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[0_u8, 1, 2, 3]);
+    /// big_int.apply_diff(&[(1, 3), (3, 0)]);
+    ///
+    /// assert!(big_int.peek_all::<u8>() == vec![0, 3, 2, 0]);
+    /// ```
+    /// # Panics
+    ///
+    /// This method may panic if any index is out of bounds or any
+    /// digit is not within base4 bounds.
+    pub fn apply_diff(&mut self, diffs: &[(usize, u8)]) {
+        let len = self.total_len();
+        for (index, digit) in diffs {
+            assert!(
+                *index < len,
+                "apply_diff: index {} out of bounds (size={})",
+                index,
+                len
+            );
+            assert!(
+                *digit < 4,
+                "Base4Int only accepts value bounded within 0..=3"
+            );
+        }
+
+        for (index, digit) in diffs {
+            self.0[index / 64].write_at(index % 64, *digit);
+        }
+    }
+
+    /// Combines two equal-length integers digit by digit, applying `f`
+    /// to every pair of corresponding digits.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut a = Base4Int::new();
+    /// let mut b = Base4Int::new();
+    ///
+    /// a.push_all(&[0_u8, 1, 2, 3]);
+    /// b.push_all(&[3_u8, 3, 3, 3]);
+    ///
+    /// let sum = a.zip_map(&b, |x, y| (x + y) % 4);
+    ///
+    /// assert!(sum.peek_all::<u8>() == vec![3, 0, 1, 2]);
+    /// ```
+    /// # Panics
+    ///
+    /// This method may panic if both integers differ in length or if
+    /// `f` returns a value which is not within base4 bounds.
+    pub fn zip_map<F>(&self, other: &Base4Int, mut f: F) -> Base4Int
+    where
+        F: FnMut(u8, u8) -> u8,
+    {
+        assert!(
+            self.total_len() == other.total_len(),
+            "zip_map: length mismatch ({} != {})",
+            self.total_len(),
+            other.total_len()
+        );
+
+        let mut zipped = Base4Int::new();
+        for (a, b) in self.digits().zip(other.digits()) {
+            zipped.push(f(a, b));
+        }
+
+        zipped
+    }
+}
+
+/// Two integers are equal when they hold the same digit sequence,
+/// regardless of how those digits are split across blocks.
+///
+/// # Example
+/// ```
+/// use base4::{Base4, Base4Int};
+///
+/// let mut a = Base4Int::new();
+/// a.push_all(&[0_u8, 1, 2, 3]);
+///
+/// let b = Base4Int::from(Base4::from_u128(27, 4));
+///
+/// assert!(a == b);
+/// ```
+impl PartialEq for Base4Int {
+    fn eq(&self, other: &Self) -> bool {
+        if self.total_len() != other.total_len() {
+            return false;
+        }
+
+        let same_layout = self.0.len() == other.0.len()
+            && self
+                .0
+                .iter()
+                .zip(other.0.iter())
+                .all(|(a, b)| a.size == b.size);
+
+        if same_layout {
+            self.0.iter().zip(other.0.iter()).all(|(a, b)| a == b)
+        } else {
+            self.digits().eq(other.digits())
+        }
+    }
+}
+
+impl Eq for Base4Int {}
+
+/// Integers are ordered lexicographically by their digit sequences,
+/// digit by digit from index 0, a proper prefix ordering before the
+/// longer sequence. This matches the ordering of the equivalent
+/// `Vec<u8>` values.
+///
+/// # Example
+/// ```
+/// use base4::Base4Int;
+///
+/// let a = "0123".parse::<Base4Int>().unwrap();
+/// let b = "013".parse::<Base4Int>().unwrap();
+/// let c = "01".parse::<Base4Int>().unwrap();
+///
+/// assert!(c < a && a < b);
+/// ```
+impl Ord for Base4Int {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.digits().cmp(other.digits())
+    }
+}
+
+impl PartialOrd for Base4Int {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Hashes the length and the digit sequence, so that integers which
+/// are equal hash the same whatever their block layout.
+impl Hash for Base4Int {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.total_len());
+        self.digits().for_each(|digit| state.write_u8(digit));
+    }
+}
+
+/// Two blocks are equal when they hold the same digit sequence.
+impl PartialEq for Base4 {
+    fn eq(&self, other: &Self) -> bool {
+        self.size == other.size && self.packed == other.packed
+    }
+}
+
+impl Eq for Base4 {}
+
+/// Blocks are ordered lexicographically by their digit sequences,
+/// just like [Base4Int].
+impl Ord for Base4 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl PartialOrd for Base4 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Hashes the length and the digit sequence, just like [Base4Int].
+impl Hash for Base4 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.size);
+        self.iter().for_each(|digit| state.write_u8(digit));
+    }
+}
@@ -0,0 +1,152 @@
+use alloc::vec::Vec;
+
+use crate::{Base4Error, Base4Int};
+
+/// Shortest back-reference worth encoding, four digits also form
+/// the one-byte hash key of the match finder.
+const MIN_MATCH: usize = 4;
+
+/// Longest back-reference a single match token can describe.
+const MAX_MATCH: usize = MIN_MATCH + 0x7F;
+
+/// Farthest back-reference a match token can point to.
+const MAX_DISTANCE: usize = u16::MAX as usize;
+
+/// Longest run of digits a single literal token can carry.
+const MAX_LITERALS: usize = 0x80;
+
+impl Base4Int {
+    /// Compresses the digits with a simple LZ77-style scheme tuned for
+    /// the quaternary alphabet.
+    ///
+    /// The stream starts with the digit count as a little-endian `u64`
+    /// followed by tokens. A control byte below `0x80` introduces
+    /// `control + 1` literal digits packed 4 per byte, otherwise it
+    /// describes a back-reference of `(control & 0x7F) + 4` digits
+    /// followed by its little-endian `u16` distance.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[1_u8; 500]);
+    ///
+    /// let compressed = big_int.compress();
+    /// let restored = Base4Int::decompress(&compressed).unwrap();
+    ///
+    /// assert!(compressed.len() < 125);
+    /// assert!(restored.peek_all::<u8>() == big_int.peek_all::<u8>());
+    /// ```
+    pub fn compress(&self) -> Vec<u8> {
+        let digits = self.peek_all::<u8>();
+        let key = |index: usize| {
+            digits[index..index + MIN_MATCH]
+                .iter()
+                .fold(0, |key, digit| (key << 2) | *digit as usize)
+        };
+
+        let mut out = Vec::with_capacity(8 + digits.len() / 4);
+        out.extend_from_slice(&(digits.len() as u64).to_le_bytes());
+
+        let mut heads = [usize::MAX; 256];
+        let mut literals = 0;
+        let mut index = 0;
+
+        while index + MIN_MATCH <= digits.len() {
+            let candidate = core::mem::replace(&mut heads[key(index)], index);
+
+            if candidate != usize::MAX && index - candidate <= MAX_DISTANCE {
+                let length = (0..MAX_MATCH.min(digits.len() - index))
+                    .take_while(|offset| digits[candidate + offset] == digits[index + offset])
+                    .count();
+
+                if length >= MIN_MATCH {
+                    write_literals(&mut out, &digits[literals..index]);
+                    out.push(0x80 | (length - MIN_MATCH) as u8);
+                    out.extend_from_slice(&((index - candidate) as u16).to_le_bytes());
+
+                    let end = index + length;
+                    (index + 1..end.min(digits.len() + 1 - MIN_MATCH))
+                        .for_each(|position| heads[key(position)] = position);
+
+                    index = end;
+                    literals = end;
+                    continue;
+                }
+            }
+
+            index += 1;
+        }
+
+        write_literals(&mut out, &digits[literals..]);
+        out
+    }
+
+    /// Restores a `Base4Int` from a stream produced by [Base4Int::compress].
+    ///
+    /// Returns [Base4Error::Malformed] with the offending byte offset if
+    /// the stream is truncated, holds an invalid back-reference or does
+    /// not decode to exactly the announced number of digits.
+    pub fn decompress(bytes: &[u8]) -> Result<Base4Int, Base4Error> {
+        let header = bytes.get(..8).ok_or(Base4Error::Malformed { offset: 0 })?;
+        let count = u64::from_le_bytes(header.try_into().unwrap()) as usize;
+
+        let mut digits = Vec::new();
+        let mut offset = 8;
+
+        while digits.len() < count {
+            let token = offset;
+            let control = *bytes.get(offset).ok_or(Base4Error::Malformed { offset })? as usize;
+            offset += 1;
+
+            if control < MAX_LITERALS {
+                let length = control + 1;
+                let packed = bytes
+                    .get(offset..offset + length.div_ceil(4))
+                    .ok_or(Base4Error::Malformed { offset })?;
+
+                digits.extend(
+                    (0..length).map(|index| (packed[index / 4] >> (6 - 2 * (index % 4))) & 0b11),
+                );
+                offset += packed.len();
+            } else {
+                let length = (control & 0x7F) + MIN_MATCH;
+                let distance = bytes
+                    .get(offset..offset + 2)
+                    .map(|raw| u16::from_le_bytes([raw[0], raw[1]]) as usize)
+                    .ok_or(Base4Error::Malformed { offset })?;
+
+                if distance == 0 || distance > digits.len() {
+                    return Err(Base4Error::Malformed { offset: token });
+                }
+
+                let start = digits.len() - distance;
+                (start..start + length).for_each(|index| digits.push(digits[index]));
+                offset += 2;
+            }
+
+            if digits.len() > count {
+                return Err(Base4Error::Malformed { offset: token });
+            }
+        }
+
+        if offset != bytes.len() {
+            return Err(Base4Error::Malformed { offset });
+        }
+
+        Ok(Base4Int::build_from_chunks(&[&digits]))
+    }
+}
+
+/// Writes `digits` as a sequence of literal tokens.
+fn write_literals(out: &mut Vec<u8>, digits: &[u8]) {
+    for chunk in digits.chunks(MAX_LITERALS) {
+        out.push((chunk.len() - 1) as u8);
+        out.extend(chunk.chunks(4).map(|quad| {
+            quad.iter()
+                .enumerate()
+                .fold(0, |byte, (index, digit)| byte | digit << (6 - 2 * index))
+        }));
+    }
+}
@@ -0,0 +1,208 @@
+use alloc::{string::String, vec::Vec};
+#[cfg(feature = "std")]
+use std::io::{self, Read, Write};
+
+#[cfg(feature = "std")]
+use crate::bytes::{read_record, write_record};
+use crate::{Base4Error, Base4Int};
+
+/// Nucleotide represented by each base4 digit, so that digit `d`
+/// encodes `NUCLEOTIDES[d]`.
+pub const NUCLEOTIDES: [char; 4] = ['A', 'C', 'G', 'T'];
+
+impl Base4Int {
+    /// Encodes a nucleotide sequence, mapping `A, C, G, T` to
+    /// `0, 1, 2, 3` regardless of case.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::{Base4Error, Base4Int};
+    ///
+    /// let big_int = Base4Int::from_dna("ACgt").unwrap();
+    /// assert!(big_int.peek_all::<u8>() == vec![0, 1, 2, 3]);
+    ///
+    /// let err = Base4Int::from_dna("ACNT").unwrap_err();
+    /// assert!(err == Base4Error::InvalidDigit { ch: 'N', position: 2 });
+    /// ```
+    /// Returns [Base4Error::InvalidDigit] for the first character
+    /// which is not a nucleotide, its position counting characters.
+    pub fn from_dna(seq: &str) -> Result<Base4Int, Base4Error> {
+        let mut int = Base4Int::new();
+        for (position, ch) in seq.chars().enumerate() {
+            let upper = ch.to_ascii_uppercase();
+            match NUCLEOTIDES
+                .iter()
+                .position(|nucleotide| *nucleotide == upper)
+            {
+                Some(digit) => int.push(digit as u8),
+                None => return Err(Base4Error::InvalidDigit { ch, position }),
+            }
+        }
+
+        Ok(int)
+    }
+
+    /// Decodes the digits into an upper case nucleotide sequence,
+    /// mapping `0, 1, 2, 3` to `A, C, G, T`.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[3_u8, 2, 1, 0]);
+    ///
+    /// assert!(big_int.to_dna() == "TGCA");
+    /// ```
+    pub fn to_dna(&self) -> String {
+        self.digits()
+            .map(|digit| NUCLEOTIDES[digit as usize])
+            .collect()
+    }
+
+    /// Reverse-complements the digits in place, mapping every digit
+    /// `d` to `3 - d` and reversing their order.
+    ///
+    /// Both steps are applied in a single pass over the blocks, each
+    /// block being reversed and complemented with bit-parallel word
+    /// operations.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[0_u8, 0, 1, 2]);
+    /// big_int.rc_inplace();
+    ///
+    /// assert!(big_int.peek_all::<u8>() == vec![1, 2, 3, 3]);
+    /// ```
+    pub fn rc_inplace(&mut self) {
+        self.rebuild_reversed(true);
+    }
+
+    /// Consumes `self` and lazily yields every digit as its nucleotide
+    /// character, mapping `0, 1, 2, 3` to `A, C, G, T`.
+    ///
+    /// Blocks are decoded one at a time, so the whole sequence never
+    /// needs to be materialized as a `String`.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[0_u8, 1, 2, 3]);
+    ///
+    /// assert!(big_int.into_dna_iter().collect::<String>() == "ACGT");
+    /// ```
+    pub fn into_dna_iter(self) -> impl Iterator<Item = char> {
+        self.0.into_iter().flat_map(|block| {
+            (0..block.size).map(move |index| NUCLEOTIDES[block.peek_at::<usize>(index)])
+        })
+    }
+
+    /// Returns the GC skew of the whole sequence, that is the number
+    /// of `G` (2) digits minus the number of `C` (1) digits.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[2_u8, 2, 1, 0, 3]);
+    ///
+    /// assert!(big_int.gc_skew() == 1);
+    /// ```
+    pub fn gc_skew(&self) -> i64 {
+        self.0
+            .iter()
+            .map(|block| {
+                block.matches(2).count_ones() as i64 - block.matches(1).count_ones() as i64
+            })
+            .sum()
+    }
+
+    /// Returns the running GC skew before every position and after the
+    /// last one, so the curve holds `total_len() + 1` values starting
+    /// at zero. Its minimum marks the replication origin.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[1_u8, 2, 2]);
+    ///
+    /// assert!(big_int.gc_skew_cumulative() == vec![0, -1, 0, 1]);
+    /// ```
+    pub fn gc_skew_cumulative(&self) -> Vec<i64> {
+        let mut skew = Vec::with_capacity(self.total_len() + 1);
+        skew.push(0);
+
+        let mut current = 0;
+        for digit in self.digits() {
+            current += match digit {
+                1 => -1,
+                2 => 1,
+                _ => 0,
+            };
+            skew.push(current);
+        }
+
+        skew
+    }
+
+    /// Writes the sequence in a simplified 2bit-like layout: the name
+    /// length as a little-endian `u32`, the UTF-8 name, the number of
+    /// bases as a little-endian `u64` and the bases packed 4 per byte
+    /// with `A=0, C=1, G=2, T=3`.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[0_u8, 1, 2, 3]);
+    ///
+    /// let mut out = Vec::new();
+    /// big_int.write_2bit("chr1", &mut out).unwrap();
+    ///
+    /// let (name, read) = Base4Int::read_2bit(&mut out.as_slice()).unwrap();
+    ///
+    /// assert!(name == "chr1");
+    /// assert!(read.peek_all::<u8>() == vec![0, 1, 2, 3]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn write_2bit<W: Write>(&self, name: &str, w: &mut W) -> io::Result<()> {
+        let name_len = u32::try_from(name.len())
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "sequence name too long"))?;
+
+        w.write_all(&name_len.to_le_bytes())?;
+        w.write_all(name.as_bytes())?;
+        write_record(self, &mut Vec::new(), w)
+    }
+
+    /// Reads back a named sequence written by [Base4Int::write_2bit].
+    ///
+    /// Fails with [io::ErrorKind::InvalidData] if the name is not valid
+    /// UTF-8, or [io::ErrorKind::UnexpectedEof] if the input is
+    /// truncated.
+    #[cfg(feature = "std")]
+    pub fn read_2bit<R: Read>(r: &mut R) -> io::Result<(String, Base4Int)> {
+        let mut name_len = [0_u8; 4];
+        r.read_exact(&mut name_len)?;
+
+        let mut name = Vec::new();
+        let name_len = u32::from_le_bytes(name_len) as u64;
+        if r.by_ref().take(name_len).read_to_end(&mut name)? as u64 != name_len {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+
+        let name = String::from_utf8(name)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        let int = read_record(r)?.ok_or(io::Error::from(io::ErrorKind::UnexpectedEof))?;
+
+        Ok((name, int))
+    }
+}
@@ -0,0 +1,76 @@
+use core::fmt;
+
+/// Errors reported by the fallible operations of [Base4Int](crate::Base4Int).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Base4Error {
+    /// The value does not fit into a single base4 digit.
+    OutOfRange { value: u128 },
+
+    /// The input buffer holds fewer bytes than required to decode
+    /// the requested number of digits.
+    BufferTooShort { required: usize, actual: usize },
+
+    /// A character which does not map onto a base4 digit was found
+    /// at the given position of the input.
+    InvalidDigit { ch: char, position: usize },
+
+    /// The encoded input is malformed at the given byte offset.
+    Malformed { offset: usize },
+
+    /// The block at the given index is not full although it is not
+    /// the last one.
+    PartialBlock { index: usize, size: usize },
+
+    /// An empty pattern was asked to fill a non-zero number of digits.
+    EmptyPattern,
+}
+
+impl fmt::Display for Base4Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutOfRange { value } => write!(
+                f,
+                "Base4Int only accepts value bounded within 0..=3, got {value}"
+            ),
+            Self::BufferTooShort { required, actual } => write!(
+                f,
+                "buffer too short: required {required} bytes, got {actual}"
+            ),
+            Self::InvalidDigit { ch, position } => {
+                write!(f, "invalid digit {ch:?} at position {position}")
+            }
+            Self::Malformed { offset } => write!(f, "malformed input at byte {offset}"),
+            Self::PartialBlock { index, size } => {
+                write!(f, "block {index} holds {size} digits, expected 64")
+            }
+            Self::EmptyPattern => write!(f, "cannot tile an empty pattern"),
+        }
+    }
+}
+
+impl core::error::Error for Base4Error {}
+
+/// Errors reported by [Base4::try_push](crate::Base4::try_push), telling
+/// apart the two reasons a single block can refuse a value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Base4PushError {
+    /// The block already holds 64 digits.
+    Full,
+
+    /// The value does not fit into a single base4 digit.
+    OutOfRange { value: u128 },
+}
+
+impl fmt::Display for Base4PushError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Full => write!(f, "Base4 block is full, it holds 64 digits"),
+            Self::OutOfRange { value } => write!(
+                f,
+                "Base4Int only accepts value bounded within 0..=3, got {value}"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for Base4PushError {}
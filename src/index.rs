@@ -0,0 +1,217 @@
+use alloc::vec::Vec;
+
+use crate::Base4Int;
+
+/// Precomputed rank/select index over a borrowed [Base4Int].
+///
+/// It stores the cumulative count of every digit before each block,
+/// so queries only need a block lookup plus a popcount within a
+/// single block instead of a scan over the whole sequence.
+///
+/// # Example
+/// ```
+/// use base4::Base4Int;
+///
+/// let mut big_int = Base4Int::new();
+/// big_int.push_all(&[3_u8; 200]);
+///
+/// let index = big_int.build_index();
+///
+/// assert!(index.rank(3, 150) == 150);
+/// assert!(index.select(3, 199) == Some(199));
+/// ```
+#[derive(Debug)]
+pub struct WaveletIndex<'a> {
+    int: &'a Base4Int,
+
+    /// Occurrences of every digit before each block, with a final
+    /// entry holding the totals.
+    prefix: Vec<[usize; 4]>,
+}
+
+impl WaveletIndex<'_> {
+    /// Returns the number of occurrences of `digit` in `[0, index)`,
+    /// matching [Base4Int::rank].
+    ///
+    /// # Panics
+    ///
+    /// This method may panic if `digit` is not within base4 bounds.
+    pub fn rank(&self, digit: u8, index: usize) -> usize {
+        assert!(
+            digit < 4,
+            "Base4Int only accepts value bounded within 0..=3"
+        );
+
+        let index = index.min(self.len());
+        let (block_idx, rest) = (index / 64, index % 64);
+        let mut count = self.prefix[block_idx][digit as usize];
+
+        if rest > 0 {
+            let block = &self.int.0[block_idx];
+            count += (block.matches(digit) >> (2 * (block.size - rest))).count_ones() as usize;
+        }
+
+        count
+    }
+
+    /// Returns the position of the `n`-th occurrence of `digit`,
+    /// counting from zero, matching [Base4Int::select].
+    ///
+    /// # Panics
+    ///
+    /// This method may panic if `digit` is not within base4 bounds.
+    pub fn select(&self, digit: u8, n: usize) -> Option<usize> {
+        assert!(
+            digit < 4,
+            "Base4Int only accepts value bounded within 0..=3"
+        );
+
+        let digit_idx = digit as usize;
+        if n >= self.prefix[self.prefix.len() - 1][digit_idx] {
+            return None;
+        }
+
+        let block_idx = self.prefix.partition_point(|counts| counts[digit_idx] <= n) - 1;
+        let block = &self.int.0[block_idx];
+        let nth = n - self.prefix[block_idx][digit_idx];
+
+        Some(block_idx * 64 + select_in_block(block.matches(digit), block.size, nth))
+    }
+
+    /// Returns the number of digits of the indexed integer.
+    fn len(&self) -> usize {
+        let last = self.prefix[self.prefix.len() - 1];
+        last.iter().sum()
+    }
+}
+
+impl Base4Int {
+    /// Lazily yields the index of every digit satisfying `f`, in
+    /// increasing order.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[0_u8, 2, 1, 3]);
+    ///
+    /// let positions: Vec<usize> = big_int.positions_where(|digit| digit >= 2).collect();
+    ///
+    /// assert!(positions == vec![1, 3]);
+    /// ```
+    pub fn positions_where<F>(&self, mut f: F) -> impl Iterator<Item = usize>
+    where
+        F: FnMut(u8) -> bool,
+    {
+        self.digits()
+            .enumerate()
+            .filter_map(move |(index, digit)| f(digit).then_some(index))
+    }
+
+    /// Builds a [WaveletIndex] answering [Base4Int::rank] and
+    /// [Base4Int::select] queries in sub-linear time.
+    pub fn build_index(&self) -> WaveletIndex<'_> {
+        let mut prefix = Vec::with_capacity(self.total_blocks() + 1);
+        let mut counts = [0; 4];
+        prefix.push(counts);
+
+        for block in self.0.iter() {
+            (0..4_u8).for_each(|digit| {
+                counts[digit as usize] += block.matches(digit).count_ones() as usize
+            });
+            prefix.push(counts);
+        }
+
+        WaveletIndex { int: self, prefix }
+    }
+
+    /// Returns the number of occurrences of `digit` within the first
+    /// `index` digits, i.e. in `[0, index)`. An `index` past the end
+    /// counts over the whole sequence.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[1_u8, 0, 1, 1]);
+    ///
+    /// assert!(big_int.rank(1, 3) == 2);
+    /// assert!(big_int.rank(1, 100) == 3);
+    /// ```
+    /// # Panics
+    ///
+    /// This method may panic if `digit` is not within base4 bounds.
+    pub fn rank(&self, digit: u8, index: usize) -> usize {
+        assert!(
+            digit < 4,
+            "Base4Int only accepts value bounded within 0..=3"
+        );
+
+        let index = index.min(self.total_len());
+        let (blocks, rest) = (index / 64, index % 64);
+
+        let mut count: usize = self
+            .0
+            .iter()
+            .take(blocks)
+            .map(|block| block.matches(digit).count_ones() as usize)
+            .sum();
+
+        if rest > 0 {
+            let block = &self.0[blocks];
+            count += (block.matches(digit) >> (2 * (block.size - rest))).count_ones() as usize;
+        }
+
+        count
+    }
+
+    /// Returns the position of the `n`-th occurrence of `digit`,
+    /// counting from zero, so that `select(d, rank(d, i)) == Some(i)`
+    /// whenever the digit at `i` is `d`.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[1_u8, 0, 1, 1]);
+    ///
+    /// assert!(big_int.select(1, 1) == Some(2));
+    /// assert!(big_int.select(1, 3) == None);
+    /// ```
+    /// Returns `None` if there are fewer than `n + 1` occurrences.
+    ///
+    /// # Panics
+    ///
+    /// This method may panic if `digit` is not within base4 bounds.
+    pub fn select(&self, digit: u8, n: usize) -> Option<usize> {
+        assert!(
+            digit < 4,
+            "Base4Int only accepts value bounded within 0..=3"
+        );
+
+        let mut n = n;
+        for (block_idx, block) in self.0.iter().enumerate() {
+            let matches = block.matches(digit);
+            let count = matches.count_ones() as usize;
+
+            if n < count {
+                return Some(block_idx * 64 + select_in_block(matches, block.size, n));
+            }
+            n -= count;
+        }
+
+        None
+    }
+}
+
+/// Returns the index of the `n`-th set group in a `matches` word of
+/// a block holding `size` digits.
+fn select_in_block(matches: u128, size: usize, n: usize) -> usize {
+    (0..size)
+        .filter(|index| (matches >> (2 * (size - index - 1))) & 1 == 1)
+        .nth(n)
+        .unwrap()
+}
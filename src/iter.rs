@@ -0,0 +1,283 @@
+use alloc::collections::{VecDeque, vec_deque};
+use core::{cell::Cell, iter::FlatMap, ops::Deref};
+
+use crate::{Base4, Base4Int, digit_mask};
+
+/// Mutable handle to a single digit yielded by [Base4Int::iter_mut](crate::Base4Int::iter_mut).
+///
+/// It derefs to the current digit, and any value written with
+/// [DigitMut::set] is packed back into its block when the handle is
+/// dropped.
+#[derive(Debug)]
+pub struct DigitMut<'a> {
+    packed: &'a Cell<u128>,
+    shift: usize,
+    value: u8,
+}
+
+impl<'a> DigitMut<'a> {
+    pub(crate) fn new(packed: &'a Cell<u128>, shift: usize) -> Self {
+        let value = ((packed.get() >> shift) & 0b11) as u8;
+        Self {
+            packed,
+            shift,
+            value,
+        }
+    }
+
+    /// Overwrites the current digit with `value`.
+    ///
+    /// # Panics
+    ///
+    /// This method may panic if `value` is not within base4 bounds.
+    pub fn set(&mut self, value: u8) {
+        assert!(
+            value < 4,
+            "DigitMut only accepts value bounded within 0..=3"
+        );
+        self.value = value;
+    }
+}
+
+impl Deref for DigitMut<'_> {
+    type Target = u8;
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl Drop for DigitMut<'_> {
+    fn drop(&mut self) {
+        let cleared = self.packed.get() & !(0b11 << self.shift);
+        self.packed
+            .set(cleared | (self.value as u128) << self.shift);
+    }
+}
+
+/// Borrowing iterator over the digits of a [Base4] block, created by
+/// [Base4::iter](crate::Base4::iter).
+///
+/// Digits are yielded in the original order in which they were packed,
+/// and can be consumed from either end.
+#[derive(Debug, Clone)]
+pub struct Base4Iter<'a> {
+    codec: &'a Base4,
+    front: usize,
+    back: usize,
+}
+
+impl<'a> Base4Iter<'a> {
+    pub(crate) fn new(codec: &'a Base4) -> Self {
+        Self {
+            codec,
+            front: 0,
+            back: codec.len(),
+        }
+    }
+}
+
+impl Iterator for Base4Iter<'_> {
+    type Item = u8;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.front += 1;
+        Some(self.codec.peek_at(self.front - 1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        (remaining, Some(remaining))
+    }
+}
+
+impl DoubleEndedIterator for Base4Iter<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        Some(self.codec.peek_at(self.back))
+    }
+}
+
+impl ExactSizeIterator for Base4Iter<'_> {}
+
+/// Owning iterator over the digits of a [Base4Int], created by its
+/// [IntoIterator] implementation.
+///
+/// Blocks are pulled off the front of the deque as they are consumed,
+/// and digits are yielded in the original order in which they were
+/// packed.
+///
+/// # Example
+/// ```
+/// use base4::Base4Int;
+///
+/// let mut big_int = Base4Int::new();
+/// big_int.push_all(&[0_u8, 1, 2, 3]);
+///
+/// let mut digits = Vec::new();
+/// for digit in big_int {
+///     digits.push(digit);
+/// }
+///
+/// assert!(digits == vec![0, 1, 2, 3]);
+/// ```
+#[derive(Debug)]
+pub struct IntoIter {
+    blocks: VecDeque<Base4>,
+    front: usize,
+    remaining: usize,
+}
+
+impl Iterator for IntoIter {
+    type Item = u8;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let block = self.blocks.front()?;
+            if self.front < block.size {
+                let digit = block.peek_at(self.front);
+                self.front += 1;
+                self.remaining -= 1;
+                return Some(digit);
+            }
+
+            self.blocks.pop_front();
+            self.front = 0;
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for IntoIter {}
+
+impl IntoIterator for Base4Int {
+    type Item = u8;
+    type IntoIter = IntoIter;
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            remaining: self.total_len(),
+            blocks: self.0,
+            front: 0,
+        }
+    }
+}
+
+/// Draining iterator over a range of digits of a [Base4Int], created
+/// by [Base4Int::drain](crate::Base4Int::drain).
+///
+/// When dropped, the digits after the drained range are appended back
+/// to the prefix, re-packed into full blocks.
+///
+/// Like [alloc::vec::Drain], leaking the iterator (e.g. with
+/// [core::mem::forget]) is safe but loses the digits past the range.
+#[derive(Debug)]
+pub struct Drain<'a> {
+    int: &'a mut Base4Int,
+    digits: IntoIter,
+    tail: Base4Int,
+}
+
+impl<'a> Drain<'a> {
+    pub(crate) fn new(int: &'a mut Base4Int, drained: Base4Int, tail: Base4Int) -> Self {
+        Self {
+            int,
+            digits: drained.into_iter(),
+            tail,
+        }
+    }
+}
+
+impl Iterator for Drain<'_> {
+    type Item = u8;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.digits.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.digits.size_hint()
+    }
+}
+
+impl ExactSizeIterator for Drain<'_> {}
+
+impl Drop for Drain<'_> {
+    fn drop(&mut self) {
+        self.int.append(core::mem::take(&mut self.tail));
+    }
+}
+
+/// Iterator over the overlapping windows of `n` digits of a
+/// [Base4Int], created by [Base4Int::windows](crate::Base4Int::windows).
+///
+/// Every window is rolled forward by one digit from the previous one,
+/// so windows spanning two blocks cost no more than any other.
+#[derive(Debug, Clone)]
+pub struct Windows<'a> {
+    digits: Digits<'a>,
+    n: usize,
+    packed: u128,
+    remaining: usize,
+}
+
+/// Digits of every block of a [Base4Int], in order.
+type Digits<'a> =
+    FlatMap<vec_deque::Iter<'a, Base4>, Base4Iter<'a>, fn(&'a Base4) -> Base4Iter<'a>>;
+
+impl<'a> Windows<'a> {
+    /// Creates the iterator, rolling in the first `n - 1` digits. It
+    /// is empty when `n` is larger than the number of elements, in
+    /// which case `n` is never used to mask a window.
+    pub(crate) fn new(int: &'a Base4Int, n: usize) -> Self {
+        let mut windows = Self {
+            digits: int.0.iter().flat_map(Base4::iter as fn(_) -> _),
+            n,
+            packed: 0,
+            remaining: (int.total_len() + 1).saturating_sub(n),
+        };
+
+        if windows.remaining > 0 {
+            for _ in 1..n {
+                windows.roll();
+            }
+        }
+
+        windows
+    }
+
+    /// Shifts the next digit into the packed window.
+    fn roll(&mut self) {
+        if let Some(digit) = self.digits.next() {
+            self.packed = ((self.packed << 2) | digit as u128) & digit_mask(self.n);
+        }
+    }
+}
+
+impl Iterator for Windows<'_> {
+    type Item = Base4;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        self.roll();
+        self.remaining -= 1;
+        Some(Base4 {
+            size: self.n,
+            packed: self.packed,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for Windows<'_> {}
@@ -0,0 +1,285 @@
+use alloc::{collections::VecDeque, vec, vec::Vec};
+#[cfg(feature = "std")]
+use std::collections::{HashMap, hash_map::Entry};
+
+use crate::{Base4Int, Windows};
+
+impl Base4Int {
+    /// Counts the occurrences of every forward k-mer, keyed by its
+    /// packed base4 encoding (first digit in the most significant bits).
+    ///
+    /// The k-mers are rolling-encoded in a single pass over the digits.
+    /// An empty map is returned if `k` is larger than the number of
+    /// elements.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[0_u8, 1, 0, 1, 0]);
+    ///
+    /// let counts = big_int.count_kmers(2);
+    ///
+    /// assert!(counts[&0b00_01] == 2);
+    /// assert!(counts[&0b01_00] == 2);
+    /// ```
+    /// # Panics
+    ///
+    /// This method may panic if `k` is not within `1..=64`.
+    #[cfg(feature = "std")]
+    pub fn count_kmers(&self, k: usize) -> HashMap<u128, usize> {
+        let mut counts = HashMap::new();
+        for kmer in self.kmers(k) {
+            *counts.entry(kmer).or_insert(0) += 1;
+        }
+
+        counts
+    }
+
+    /// Returns the most frequent forward k-mer along with its count,
+    /// ties being broken in favour of the smaller encoding.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[2_u8, 2, 2, 1]);
+    ///
+    /// assert!(big_int.most_common_kmer(2) == Some((0b10_10, 2)));
+    /// assert!(big_int.most_common_kmer(5) == None);
+    /// ```
+    /// Returns `None` if `k` is larger than the number of elements.
+    ///
+    /// # Panics
+    ///
+    /// This method may panic if `k` is not within `1..=64` while not
+    /// being larger than the number of elements.
+    #[cfg(feature = "std")]
+    pub fn most_common_kmer(&self, k: usize) -> Option<(u128, usize)> {
+        if k > self.total_len() {
+            return None;
+        }
+
+        self.count_kmers(k)
+            .into_iter()
+            .max_by(|(a, a_count), (b, b_count)| a_count.cmp(b_count).then(b.cmp(a)))
+    }
+
+    /// Finds the longest run of digits occurring at least twice, the
+    /// occurrences being allowed to overlap. Returns the two starting
+    /// positions along with the length.
+    ///
+    /// The length is binary searched, hashing every window of the
+    /// candidate length. Among repeats of the longest length, the one
+    /// whose second occurrence comes first is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[0_u8, 1, 2, 3, 1, 2, 3, 0]);
+    ///
+    /// assert!(big_int.longest_repeat() == (1, 4, 3));
+    /// ```
+    /// Returns `(0, 0, 0)` if no digit occurs twice.
+    #[cfg(feature = "std")]
+    pub fn longest_repeat(&self) -> (usize, usize, usize) {
+        let digits = self.peek_all::<u8>();
+        let find = |len: usize| {
+            let mut seen = HashMap::with_capacity(digits.len() + 1 - len);
+            for (start, window) in digits.windows(len).enumerate() {
+                match seen.entry(window) {
+                    Entry::Occupied(first) => return Some((*first.get(), start)),
+                    Entry::Vacant(slot) => {
+                        slot.insert(start);
+                    }
+                }
+            }
+            None
+        };
+
+        let mut longest = (0, 0, 0);
+        let (mut low, mut high) = (1, digits.len().saturating_sub(1));
+
+        while low <= high {
+            let mid = low + (high - low) / 2;
+            match find(mid) {
+                Some((first, second)) => {
+                    longest = (first, second, mid);
+                    low = mid + 1;
+                }
+                None => high = mid - 1,
+            }
+        }
+
+        longest
+    }
+
+    /// Computes the forward minimizers: for every window of `w`
+    /// consecutive k-mers, the smallest k-mer along with its position,
+    /// ties being broken in favour of the leftmost one.
+    ///
+    /// Consecutive windows selecting the same k-mer occurrence are
+    /// reported once. The minimum is tracked with a monotonic deque,
+    /// so the whole sequence is processed in linear time.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[3_u8, 1, 2, 0, 3]);
+    ///
+    /// assert!(big_int.minimizers(1, 2) == vec![(1, 1), (3, 0)]);
+    /// ```
+    /// Returns an empty vector if there are fewer than `w` k-mers.
+    ///
+    /// # Panics
+    ///
+    /// This method may panic if `k` is not within `1..=64` or if `w`
+    /// is zero.
+    pub fn minimizers(&self, k: usize, w: usize) -> Vec<(usize, u128)> {
+        assert!(w > 0, "minimizers: window must be non-zero");
+
+        let mut minimizers: Vec<(usize, u128)> = Vec::new();
+        let mut window: VecDeque<(usize, u128)> = VecDeque::with_capacity(w);
+
+        for (position, kmer) in self.kmers(k).enumerate() {
+            while window.back().is_some_and(|(_, back)| *back > kmer) {
+                window.pop_back();
+            }
+            window.push_back((position, kmer));
+
+            while window
+                .front()
+                .is_some_and(|(front, _)| front + w <= position)
+            {
+                window.pop_front();
+            }
+
+            if position + 1 >= w {
+                let selected = window[0];
+                if minimizers.last() != Some(&selected) {
+                    minimizers.push(selected);
+                }
+            }
+        }
+
+        minimizers
+    }
+
+    /// Returns the index of the first occurrence of `pattern`, which
+    /// may span several blocks.
+    ///
+    /// A packed window is rolled over the digits and compared with the
+    /// packed pattern, a word at a time. Patterns longer than 64 digits
+    /// are searched digit by digit with Knuth-Morris-Pratt, so the
+    /// search stays linear either way. An empty pattern is found at
+    /// index 0.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[0_u8, 1, 2, 1, 2, 3]);
+    ///
+    /// assert!(big_int.find(&[1, 2, 3]) == Some(3));
+    /// assert!(big_int.find(&[3, 3]) == None);
+    /// ```
+    /// Returns `None` if `pattern` does not occur, which is always the
+    /// case when it is longer than `self`.
+    ///
+    /// # Panics
+    ///
+    /// This method may panic if any digit of `pattern` is not within
+    /// base4 bounds.
+    pub fn find(&self, pattern: &[u8]) -> Option<usize> {
+        assert!(
+            pattern.iter().all(|digit| *digit < 4),
+            "Base4Int only accepts value bounded within 0..=3"
+        );
+
+        if pattern.is_empty() {
+            return Some(0);
+        }
+        if pattern.len() > self.total_len() {
+            return None;
+        }
+        if pattern.len() > 64 {
+            return search(self.digits(), pattern);
+        }
+
+        let target = pattern
+            .iter()
+            .fold(0_u128, |packed, digit| (packed << 2) | *digit as u128);
+
+        self.kmers(pattern.len()).position(|kmer| kmer == target)
+    }
+
+    /// Returns `true` if `pattern` occurs anywhere within `self`, see
+    /// [Base4Int::find].
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[0_u8, 1, 2, 3]);
+    ///
+    /// assert!(big_int.contains(&[1, 2]));
+    /// assert!(!big_int.contains(&[2, 1]));
+    /// ```
+    /// # Panics
+    ///
+    /// This method may panic if any digit of `pattern` is not within
+    /// base4 bounds.
+    pub fn contains(&self, pattern: &[u8]) -> bool {
+        self.find(pattern).is_some()
+    }
+
+    /// Rolling-encodes every forward k-mer in order.
+    fn kmers(&self, k: usize) -> impl Iterator<Item = u128> + '_ {
+        assert!(
+            (1..=64).contains(&k),
+            "k-mer length {} out of range 1..=64",
+            k
+        );
+
+        Windows::new(self, k).map(|window| window.packed)
+    }
+}
+
+/// Knuth-Morris-Pratt search of a non-empty `needle` within
+/// `haystack`, returning the start of the first match.
+fn search(haystack: impl Iterator<Item = u8>, needle: &[u8]) -> Option<usize> {
+    let mut failure = vec![0; needle.len()];
+    let mut matched = 0;
+    for index in 1..needle.len() {
+        while matched > 0 && needle[index] != needle[matched] {
+            matched = failure[matched - 1];
+        }
+        if needle[index] == needle[matched] {
+            matched += 1;
+        }
+        failure[index] = matched;
+    }
+
+    matched = 0;
+    for (index, digit) in haystack.enumerate() {
+        while matched > 0 && digit != needle[matched] {
+            matched = failure[matched - 1];
+        }
+        if digit == needle[matched] {
+            matched += 1;
+        }
+        if matched == needle.len() {
+            return Some(index + 1 - needle.len());
+        }
+    }
+
+    None
+}
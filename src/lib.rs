@@ -1,4 +1,36 @@
-use std::{collections::VecDeque, ops::Index};
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+mod arith;
+mod bytes;
+mod compare;
+mod compress;
+mod dna;
+mod error;
+mod index;
+mod iter;
+mod kmer;
+#[cfg(feature = "rand")]
+mod random;
+mod rotation;
+#[cfg(feature = "serde")]
+mod serde_impl;
+mod stats;
+mod transform;
+
+pub use dna::NUCLEOTIDES;
+pub use error::{Base4Error, Base4PushError};
+pub use index::WaveletIndex;
+pub use iter::{Base4Iter, DigitMut, Drain, IntoIter, Windows};
+
+use alloc::{collections::VecDeque, format, string::String, vec::Vec};
+use core::{
+    cell::Cell,
+    fmt,
+    ops::{Index, Range},
+    str::FromStr,
+};
 type Base4Blocks = VecDeque<Base4>;
 
 /// A big integer represented in base-4 across multiple 64-digit blocks.
@@ -15,7 +47,7 @@ type Base4Blocks = VecDeque<Base4>;
 ///
 /// assert!(big_int.total_len() == 7);
 /// ```
-#[derive(Debug)]
+#[derive(Clone)]
 pub struct Base4Int(Base4Blocks);
 
 impl Default for Base4Int {
@@ -30,6 +62,264 @@ impl Base4Int {
         Self(Base4Blocks::new())
     }
 
+    /// Creates a new empty `Base4Int` with room for at least
+    /// `digit_count` digits, so that pushing them does not reallocate.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::with_capacity(1000);
+    /// big_int.push_all(&[1_u8; 1000]);
+    ///
+    /// assert!(big_int.total_blocks() == 16);
+    /// ```
+    pub fn with_capacity(digit_count: usize) -> Self {
+        Self(Base4Blocks::with_capacity(digit_count.div_ceil(64)))
+    }
+
+    /// Reserves room for at least `additional` more digits, growing
+    /// the block storage only when it cannot fit them already.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[1_u8; 10]);
+    /// big_int.reserve(1000);
+    ///
+    /// assert!(big_int.total_len() == 10);
+    /// ```
+    pub fn reserve(&mut self, additional: usize) {
+        let total = self.total_len() + additional;
+        self.0
+            .reserve(total.div_ceil(64).saturating_sub(self.0.len()));
+    }
+
+    /// Returns the number of blocks the storage can hold without
+    /// reallocating, each block holding up to 64 digits.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let big_int = Base4Int::with_capacity(640);
+    ///
+    /// assert!(big_int.capacity() >= 10);
+    /// ```
+    pub fn capacity(&self) -> usize {
+        self.0.capacity()
+    }
+
+    /// Shrinks the block storage as much as possible, releasing the
+    /// capacity left behind by popped blocks.
+    ///
+    /// This may reallocate and move all the remaining blocks.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[1_u8; 6400]);
+    ///
+    /// let _ = big_int.split_off(64);
+    /// big_int.shrink_to_fit();
+    ///
+    /// assert!(big_int.capacity() < 100);
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit();
+    }
+
+    /// Assembles a `Base4Int` from blocks packed separately, e.g. by
+    /// several threads, keeping their order.
+    ///
+    /// An empty last block is dropped.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::{Base4, Base4Error, Base4Int};
+    ///
+    /// let full = Base4::from_u128(0, 64);
+    /// let tail = Base4::from_u128(0b01_10, 2);
+    ///
+    /// let big_int = Base4Int::from_blocks(vec![full.clone(), tail.clone()]).unwrap();
+    /// assert!(big_int.total_len() == 66);
+    ///
+    /// let err = Base4Int::from_blocks(vec![tail, full]).unwrap_err();
+    /// assert!(err == Base4Error::PartialBlock { index: 0, size: 2 });
+    /// ```
+    /// Returns [Base4Error::PartialBlock] naming the first block which
+    /// is not full although it is not the last one.
+    pub fn from_blocks(mut blocks: Vec<Base4>) -> Result<Base4Int, Base4Error> {
+        if blocks.last().is_some_and(Base4::is_empty) {
+            blocks.pop();
+        }
+
+        let last = blocks.len().saturating_sub(1);
+        if let Some((index, block)) = blocks[..last]
+            .iter()
+            .enumerate()
+            .find(|(_, block)| block.size < 64)
+        {
+            return Err(Base4Error::PartialBlock {
+                index,
+                size: block.size,
+            });
+        }
+
+        Ok(Self(blocks.into()))
+    }
+
+    /// Consumes `self` and returns its blocks in order, every block
+    /// except the last one holding exactly 64 digits.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[2_u8; 70]);
+    ///
+    /// let blocks = big_int.into_blocks();
+    ///
+    /// assert!(blocks.len() == 2);
+    /// assert!(blocks[1].len() == 6);
+    /// ```
+    pub fn into_blocks(self) -> Vec<Base4> {
+        self.0.into()
+    }
+
+    /// Builds a `Base4Int` from several chunks of digits, packed
+    /// contiguously in the given order.
+    ///
+    /// The exact number of blocks is reserved up front and all the
+    /// chunks are packed in a single streaming pass, filling blocks
+    /// across chunk boundaries.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let big_int = Base4Int::build_from_chunks(&[&[0, 1], &[2, 3, 2]]);
+    ///
+    /// assert!(big_int.peek_all::<u8>() == vec![0, 1, 2, 3, 2]);
+    /// ```
+    /// # Panics
+    ///
+    /// This may panic if any of the digits is not within base4 bounds.
+    pub fn build_from_chunks(chunks: &[&[u8]]) -> Self {
+        let total = chunks.iter().map(|chunk| chunk.len()).sum::<usize>();
+        let mut blocks = Base4Blocks::with_capacity(total.div_ceil(64));
+        let mut codec = Base4::new();
+
+        for integer in chunks.iter().flat_map(|chunk| chunk.iter()) {
+            assert!(
+                *integer < 4,
+                "Base4Int only accepts value bounded within 0..=3"
+            );
+            codec.packed = (codec.packed << 2) | *integer as u128;
+            codec.size += 1;
+
+            if codec.size == 64 {
+                blocks.push_back(core::mem::take(&mut codec));
+            }
+        }
+
+        if codec.size > 0 {
+            blocks.push_back(codec);
+        }
+
+        Self(blocks)
+    }
+
+    /// Builds a sorted `Base4Int` holding exactly `counts[i]` copies
+    /// of every digit `i`.
+    ///
+    /// Each run is packed a whole block at a time from a repeated
+    /// digit pattern.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let big_int = Base4Int::from_counts([2, 0, 1, 3]);
+    ///
+    /// assert!(big_int.peek_all::<u8>() == vec![0, 0, 2, 3, 3, 3]);
+    /// ```
+    pub fn from_counts(counts: [usize; 4]) -> Self {
+        let mut int = Self::new();
+        for (digit, count) in counts.into_iter().enumerate() {
+            int.push_repeated(digit as u8, count);
+        }
+
+        int
+    }
+
+    /// Builds a `Base4Int` holding `count` copies of `digit`.
+    ///
+    /// Every full block is set at once from a precomputed digit
+    /// pattern rather than packing the digits one by one.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let big_int = Base4Int::repeat(3_u8, 70);
+    ///
+    /// assert!(big_int.peek_all::<u8>() == vec![3; 70]);
+    /// ```
+    /// # Panics
+    ///
+    /// This may panic if `digit` is not within base4 bounds.
+    pub fn repeat<T>(digit: T, count: usize) -> Self
+    where
+        T: Into<u128> + Copy,
+    {
+        let digit = digit.into();
+        if digit >= 4 {
+            panic!("{}", Base4Error::OutOfRange { value: digit });
+        }
+
+        let mut int = Self::with_capacity(count);
+        int.push_repeated(digit as u8, count);
+        int
+    }
+
+    /// Builds a `Base4Int` of `total_len` digits by tiling `pattern`,
+    /// the last repetition being truncated.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::{Base4Error, Base4Int};
+    ///
+    /// let big_int = Base4Int::from_pattern(&[0, 1, 2], 7).unwrap();
+    /// assert!(big_int.peek_all::<u8>() == vec![0, 1, 2, 0, 1, 2, 0]);
+    ///
+    /// let err = Base4Int::from_pattern(&[0, 4], 7).unwrap_err();
+    /// assert!(err == Base4Error::OutOfRange { value: 4 });
+    /// ```
+    /// Returns [Base4Error::OutOfRange] for the first element of
+    /// `pattern` which is not within base4 bounds, and
+    /// [Base4Error::EmptyPattern] if `pattern` is empty while
+    /// `total_len` is not zero.
+    pub fn from_pattern(pattern: &[u8], total_len: usize) -> Result<Self, Base4Error> {
+        if let Some(value) = pattern.iter().find(|digit| **digit >= 4) {
+            return Err(Base4Error::OutOfRange {
+                value: *value as u128,
+            });
+        }
+        if pattern.is_empty() && total_len > 0 {
+            return Err(Base4Error::EmptyPattern);
+        }
+
+        let mut int = Self::with_capacity(total_len);
+        int.extend(pattern.iter().copied().cycle().take(total_len));
+        Ok(int)
+    }
+
     /// Pushes a slice of integers into Base4Int. Slice can be
     /// of any number type which can be caseted to u128.
     ///
@@ -39,6 +329,7 @@ impl Base4Int {
     where
         T: Into<u128> + Copy,
     {
+        self.reserve(ints.len());
         for integer in ints {
             self.push(*integer);
         }
@@ -47,136 +338,1321 @@ impl Base4Int {
     /// Pushes a single integer into Base4Int. Integer can be
     /// of any number type which can be caseted to u128.
     ///
-    /// This may panic if the integer is not within base4 bounds.
+    /// This may panic if the integer is not within base4 bounds,
+    /// use [Base4Int::try_push] to handle that case instead.
     pub fn push<T>(&mut self, integer: T)
     where
         T: Into<u128> + Copy,
     {
-        assert!(
-            integer.into() < 4,
-            "Base4Int only accepts value bounded within 0..=3"
-        );
-        let codec = self.get_codec();
-        codec.push(integer);
+        if let Err(err) = self.try_push(integer) {
+            panic!("{err}");
+        }
     }
 
-    /// Pops a single element out of the last block first.
+    /// Pushes a single integer into Base4Int, reporting values
+    /// outside of base4 bounds instead of panicking.
     ///
-    /// It returns None if the block is empty.
-    pub fn pop(&mut self) -> Option<u8> {
-        let (out, empty) = match self.0.back_mut() {
-            Some(codec) => {
-                let out = codec.pop();
-                (out, codec.size == 0)
-            }
-            // SAFE: In most cases this would not happen since we do
-            // not keep empty containers.
-            None => panic!("Attempt to pop an empty Base4-Integer"),
-        };
-
-        // Remove and drop the empty container.
-        if empty {
-            let _ = self.0.pop_back();
+    /// # Example
+    /// ```
+    /// use base4::{Base4Error, Base4Int};
+    ///
+    /// let mut big_int = Base4Int::new();
+    ///
+    /// assert!(big_int.try_push(3_u8).is_ok());
+    /// assert!(big_int.try_push(7_u8) == Err(Base4Error::OutOfRange { value: 7 }));
+    /// assert!(big_int.total_len() == 1);
+    /// ```
+    /// Returns [Base4Error::OutOfRange] carrying the rejected value.
+    pub fn try_push<T>(&mut self, integer: T) -> Result<(), Base4Error>
+    where
+        T: Into<u128> + Copy,
+    {
+        let value = integer.into();
+        if value >= 4 {
+            return Err(Base4Error::OutOfRange { value });
         }
-        out
+
+        self.get_codec().push(value);
+        Ok(())
     }
 
-    /// Pops all the elements stored inside each base4 block in
-    /// first-in-first-out order preserving the original ordering
-    /// in whicch all elements were inserted.
+    /// Prepends a single integer, which becomes the element at
+    /// index 0, shifting every other element one position back.
     ///
-    /// This may return an empty vector if no elements are there.
-    pub fn pop_all<T>(&mut self) -> Vec<T>
+    /// Each block is shifted by one digit a whole word at a time,
+    /// handing its last digit over to the next block, so that every
+    /// block except the last one stays full. This costs `O(n / 64)`.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[1_u8, 2]);
+    /// big_int.push_front(3_u8);
+    ///
+    /// assert!(big_int.peek_all::<u8>() == vec![3, 1, 2]);
+    /// ```
+    /// # Panics
+    ///
+    /// This may panic if the integer is not within base4 bounds.
+    pub fn push_front<T>(&mut self, integer: T)
     where
-        T: From<u8> + Copy,
+        T: Into<u128> + Copy,
     {
-        if self.total_len() == 0 {
-            return vec![];
+        let mut carry = integer.into();
+        if carry >= 4 {
+            panic!("{}", Base4Error::OutOfRange { value: carry });
         }
 
-        let optimal_cap = self.0.iter().map(|block| block.size).sum();
-        let mut ints = Vec::with_capacity(optimal_cap);
+        for codec in self.0.iter_mut() {
+            if codec.size < 64 {
+                codec.packed |= carry << (2 * codec.size);
+                codec.size += 1;
+                return;
+            }
 
-        while let Some(mut codec) = self.0.pop_front() {
-            ints.extend(codec.pop_all::<T>());
+            let out = codec.packed & 0b11;
+            codec.packed = (codec.packed >> 2) | (carry << 126);
+            carry = out;
         }
 
-        ints
+        self.0.push_back(Base4 {
+            size: 1,
+            packed: carry,
+        });
     }
 
-    /// Gets the last [Base4] block if its not full, or else
-    /// allocate a new one.
-    pub fn get_codec(&mut self) -> &mut Base4 {
-        if let Some(codec) = self.0.back() {
-            if codec.size < 64 {
-                return self.0.back_mut().unwrap();
-            }
+    /// Appends `fill` digits until the number of elements reaches the
+    /// next power of two. This is a no-op if it already is one, while
+    /// an empty `Base4Int` is padded to a single digit.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[1_u8, 2, 3]);
+    /// big_int.pad_to_power_of_two(0);
+    ///
+    /// assert!(big_int.peek_all::<u8>() == vec![1, 2, 3, 0]);
+    /// ```
+    /// # Panics
+    ///
+    /// This may panic if `fill` is not within base4 bounds.
+    pub fn pad_to_power_of_two(&mut self, fill: u8) {
+        assert!(fill < 4, "Base4Int only accepts value bounded within 0..=3");
+
+        let len = self.total_len();
+        (len..len.next_power_of_two()).for_each(|_| self.push(fill));
+    }
+
+    /// Appends the digits of all `others` at the back, in order.
+    ///
+    /// Room for every block is reserved once, and blocks are merged
+    /// a whole packed word at a time, so no digit is re-pushed one by
+    /// one.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// let mut other = Base4Int::new();
+    ///
+    /// big_int.push_all(&[0_u8, 1]);
+    /// other.push_all(&[2_u8, 3]);
+    /// big_int.concat_inplace_many(vec![other]);
+    ///
+    /// assert!(big_int.peek_all::<u8>() == vec![0, 1, 2, 3]);
+    /// ```
+    pub fn concat_inplace_many(&mut self, others: Vec<Base4Int>) {
+        self.reserve(others.iter().map(Base4Int::total_len).sum());
+
+        for other in others {
+            self.append(other);
         }
-        self.0.push_back(Base4::new());
-        self.0.back_mut().unwrap()
     }
 
-    /// Peeks at a specific element by index according to the
-    /// original list from which the element were inseted without
-    /// popping the value out of `Base4Int`.
+    /// Appends the digits of `other` after the ones of `self`,
+    /// preserving their order.
+    ///
+    /// The leading digits of `other` top up the last block of `self`
+    /// first, so that every block except the last one stays full.
     ///
     /// # Example
     /// ```
     /// use base4::Base4Int;
     ///
     /// let mut big_int = Base4Int::new();
-    /// big_int.push_all(&[0_u64, 1, 2, 3, 2, 1, 0]);
+    /// let mut other = Base4Int::new();
     ///
-    /// assert!(2 == big_int.peek_at(2));
-    /// assert!(0 == big_int.peek_at(6));
+    /// big_int.push_all(&[0_u8, 1]);
+    /// other.push_all(&[2_u8, 3]);
+    /// big_int.append(other);
+    ///
+    /// assert!(big_int.peek_all::<u8>() == vec![0, 1, 2, 3]);
+    /// ```
+    pub fn append(&mut self, other: Base4Int) {
+        self.reserve(other.total_len());
+
+        for block in other.0 {
+            self.push_block(block);
+        }
+    }
+
+    /// Splits the digits at `index`, leaving `[0, index)` in `self`
+    /// and returning `[index, len)` as a new `Base4Int`.
+    ///
+    /// The block holding `index` is split in two and the suffix is
+    /// re-packed a whole word at a time, so both sides keep every
+    /// block except the last one full.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[0_u8, 1, 2, 3]);
+    ///
+    /// let suffix = big_int.split_off(1);
+    ///
+    /// assert!(big_int.peek_all::<u8>() == vec![0]);
+    /// assert!(suffix.peek_all::<u8>() == vec![1, 2, 3]);
     /// ```
     /// # Panics
     ///
-    /// This method may panic if the porvided index is out of
-    /// bounds according to the original slice.
-    pub fn peek_at<T>(&self, index: usize) -> T
-    where
-        T: From<u8> + Copy,
-    {
+    /// This method may panic if `index` is larger than the number of
+    /// elements.
+    pub fn split_off(&mut self, index: usize) -> Base4Int {
         assert!(
-            index < self.total_len(),
-            "peek_at: index {} out of bounds (size={})",
+            index <= self.total_len(),
+            "split_off: index {} out of bounds (size={})",
             index,
             self.total_len()
         );
 
-        let codec_index = index / 64;
-        let peek_index = index % 64;
-
-        self[codec_index].peek_at::<T>(peek_index)
-    }
+        let mut tail = self.0.split_off(index / 64);
+        let offset = index % 64;
+        if offset == 0 {
+            return Base4Int(tail);
+        }
 
-    /// Returns the list of all the elements packed inside the
-    /// `Base4Int` without popping.
-    ///
-    /// List will be received in the original order in which it
-    /// was packed.
-    pub fn peek_all<T>(&self) -> Vec<T>
-    where
-        T: From<u8> + Copy,
-    {
-        let mut ints = Vec::with_capacity(self.total_len());
-        for codec_idx in 0..self.total_blocks() {
-            ints.extend_from_slice(&self[codec_idx].peek_all());
+        let mut suffix = Base4Int::new();
+        if let Some(boundary) = tail.pop_front() {
+            let rest = boundary.size - offset;
+            self.0.push_back(Base4 {
+                size: offset,
+                packed: boundary.packed >> (2 * rest),
+            });
+            suffix.push_block(Base4 {
+                size: rest,
+                packed: boundary.packed & digit_mask(rest),
+            });
         }
 
-        ints
-    }
+        for block in tail {
+            suffix.push_block(block);
+        }
 
-    /// Returns the number of all the elements packed inside.
-    pub fn total_len(&self) -> usize {
-        self.0.iter().map(|block| block.size).sum()
+        suffix
+    }
+
+    /// Removes the digits within `range` and returns an iterator
+    /// yielding them in their original order.
+    ///
+    /// The digits after the range are re-packed behind the ones
+    /// before it when the returned [Drain] is dropped, even if it was
+    /// not fully consumed.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[0_u8, 1, 2, 3, 2]);
+    ///
+    /// assert!(big_int.drain(1..4).collect::<Vec<_>>() == vec![1, 2, 3]);
+    /// assert!(big_int.peek_all::<u8>() == vec![0, 2]);
+    /// ```
+    /// # Panics
+    ///
+    /// This method may panic if the range is decreasing or ends out
+    /// of bounds.
+    pub fn drain(&mut self, range: Range<usize>) -> Drain<'_> {
+        let len = self.total_len();
+        assert!(
+            range.start <= range.end && range.end <= len,
+            "drain: range {:?} out of bounds (size={})",
+            range,
+            len
+        );
+
+        let tail = self.split_off(range.end);
+        let drained = self.split_off(range.start);
+        Drain::new(self, drained, tail)
+    }
+
+    /// Pops a single element out of the last block first.
+    ///
+    /// It returns None if there are no elements.
+    pub fn pop(&mut self) -> Option<u8> {
+        let codec = self.0.back_mut()?;
+        let out = codec.pop();
+
+        // Remove and drop the empty container.
+        if codec.size == 0 {
+            let _ = self.0.pop_back();
+        }
+        out
+    }
+
+    /// Pops the first inserted element, giving a FIFO queue interface
+    /// where [Base4Int::pop] gives a LIFO one.
+    ///
+    /// Every block is shifted by one digit a whole word at a time,
+    /// pulling in the first digit of the next block, so that every
+    /// block except the last one stays full. This costs `O(n / 64)`.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[0_u8, 1, 2]);
+    ///
+    /// assert!(big_int.pop_front() == Some(0));
+    /// assert!(big_int.pop() == Some(2));
+    /// assert!(big_int.peek_all::<u8>() == vec![1]);
+    /// ```
+    /// It returns None if there are no elements.
+    pub fn pop_front(&mut self) -> Option<u8> {
+        let out = self.front()?;
+
+        let blocks = self.0.len();
+        for index in 0..blocks {
+            let size = self.0[index].size;
+            let rest = self.0[index].packed & digit_mask(size - 1);
+
+            match self.0.get(index + 1) {
+                Some(next) => {
+                    let carry = next.packed >> (2 * (next.size - 1));
+                    self.0[index].packed = (rest << 2) | carry;
+                }
+                None => {
+                    self.0[index].packed = rest;
+                    self.0[index].size -= 1;
+                }
+            }
+        }
+
+        // Remove and drop the empty container.
+        if self.0.back().is_some_and(|codec| codec.size == 0) {
+            let _ = self.0.pop_back();
+        }
+        Some(out)
+    }
+
+    /// Pops a single element out of the last block first, like
+    /// [Base4Int::pop].
+    ///
+    /// # Panics
+    ///
+    /// This method panics if there are no elements.
+    pub fn pop_expect(&mut self) -> u8 {
+        self.pop().expect("Attempt to pop an empty Base4-Integer")
+    }
+
+    /// Removes all the elements, keeping the allocated block
+    /// capacity so that later pushes do not reallocate.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[1_u8; 100]);
+    /// big_int.clear();
+    ///
+    /// assert!(big_int.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    /// Pops digits from the back as long as `pred` returns `false`,
+    /// stopping at the first digit matching it, which stays in place.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[0_u8, 3, 1, 2]);
+    ///
+    /// assert!(big_int.pop_until(|digit| digit == 3) == vec![2, 1]);
+    /// assert!(big_int.last() == Some(3));
+    /// ```
+    /// Returns the popped digits in pop order, which is every digit if
+    /// none matches.
+    pub fn pop_until<F>(&mut self, mut pred: F) -> Vec<u8>
+    where
+        F: FnMut(u8) -> bool,
+    {
+        let mut popped = Vec::new();
+        while let Some(digit) = self.last() {
+            if pred(digit) {
+                break;
+            }
+            popped.extend(self.pop());
+        }
+
+        popped
+    }
+
+    /// Pops all the elements stored inside each base4 block in
+    /// first-in-first-out order preserving the original ordering
+    /// in whicch all elements were inserted.
+    ///
+    /// This may return an empty vector if no elements are there.
+    pub fn pop_all<T>(&mut self) -> Vec<T>
+    where
+        T: From<u8> + Copy,
+    {
+        let mut ints = Vec::with_capacity(self.total_len());
+        for codec in self.0.drain(..) {
+            codec.decode_into(&mut ints);
+        }
+
+        ints
+    }
+
+    /// Gets the last [Base4] block if its not full, or else
+    /// allocate a new one.
+    pub fn get_codec(&mut self) -> &mut Base4 {
+        if let Some(codec) = self.0.back() {
+            if codec.size < 64 {
+                return self.0.back_mut().unwrap();
+            }
+        }
+        self.0.push_back(Base4::new());
+        self.0.back_mut().unwrap()
+    }
+
+    /// Peeks at a specific element by index according to the
+    /// original list from which the element were inseted without
+    /// popping the value out of `Base4Int`.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[0_u64, 1, 2, 3, 2, 1, 0]);
+    ///
+    /// assert!(2 == big_int.peek_at(2));
+    /// assert!(0 == big_int.peek_at(6));
+    /// ```
+    /// # Panics
+    ///
+    /// This method may panic if the porvided index is out of
+    /// bounds according to the original slice.
+    pub fn peek_at<T>(&self, index: usize) -> T
+    where
+        T: From<u8> + Copy,
+    {
+        assert!(
+            index < self.total_len(),
+            "peek_at: index {} out of bounds (size={})",
+            index,
+            self.total_len()
+        );
+
+        let codec_index = index / 64;
+        let peek_index = index % 64;
+
+        self[codec_index].peek_at::<T>(peek_index)
+    }
+
+    /// Overwrites the element at `index`, according to the original
+    /// list from which the elements were inserted.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[0_u8, 1, 2, 3]);
+    /// big_int.set_at(1, 3_u8);
+    ///
+    /// assert!(big_int.peek_all::<u8>() == vec![0, 3, 2, 3]);
+    /// ```
+    /// # Panics
+    ///
+    /// This method may panic if the index is out of bounds or if the
+    /// value is not within base4 bounds.
+    pub fn set_at<T>(&mut self, index: usize, value: T)
+    where
+        T: Into<u128> + Copy,
+    {
+        assert!(
+            index < self.total_len(),
+            "set_at: index {} out of bounds (size={})",
+            index,
+            self.total_len()
+        );
+
+        self.0[index / 64].set_at(index % 64, value);
+    }
+
+    /// Inserts an element at `index`, shifting all the elements
+    /// after it one position towards the back.
+    ///
+    /// Blocks before the one holding `index` are left untouched, while
+    /// the following ones are re-packed, making this `O(n)` in the
+    /// number of digits after the insertion point.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[0_u8, 1, 2]);
+    /// big_int.insert_at(1, 3_u8);
+    ///
+    /// assert!(big_int.peek_all::<u8>() == vec![0, 3, 1, 2]);
+    /// ```
+    /// # Panics
+    ///
+    /// This method may panic if `index` is larger than the number of
+    /// elements or if the value is not within base4 bounds.
+    pub fn insert_at<T>(&mut self, index: usize, value: T)
+    where
+        T: Into<u128> + Copy,
+    {
+        assert!(
+            index <= self.total_len(),
+            "insert_at: index {} out of bounds (size={})",
+            index,
+            self.total_len()
+        );
+
+        let value = value.into();
+        if value >= 4 {
+            panic!("{}", Base4Error::OutOfRange { value });
+        }
+
+        let tail = self.0.split_off(index / 64);
+        let mut digits = tail.iter().flat_map(Base4::iter).collect::<Vec<_>>();
+        digits.insert(index % 64, value as u8);
+
+        self.push_all(&digits);
+    }
+
+    /// Removes and returns the element at `index`, shifting all the
+    /// elements after it one position towards the front.
+    ///
+    /// Blocks before the one holding `index` are left untouched, while
+    /// the following ones are re-packed, making this `O(n)` in the
+    /// number of digits after the removal point.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[0_u8, 1, 2]);
+    ///
+    /// assert!(big_int.remove_at(1) == 1);
+    /// assert!(big_int.peek_all::<u8>() == vec![0, 2]);
+    /// ```
+    /// # Panics
+    ///
+    /// This method may panic if `index` is out of bounds.
+    pub fn remove_at(&mut self, index: usize) -> u8 {
+        assert!(
+            index < self.total_len(),
+            "remove_at: index {} out of bounds (size={})",
+            index,
+            self.total_len()
+        );
+
+        let tail = self.0.split_off(index / 64);
+        let mut digits = tail.iter().flat_map(Base4::iter).collect::<Vec<_>>();
+        let removed = digits.remove(index % 64);
+
+        self.push_all(&digits);
+        removed
+    }
+
+    /// Returns the list of all the elements packed inside the
+    /// `Base4Int` without popping.
+    ///
+    /// List will be received in the original order in which it
+    /// was packed.
+    pub fn peek_all<T>(&self) -> Vec<T>
+    where
+        T: From<u8> + Copy,
+    {
+        let mut ints = Vec::new();
+        self.peek_all_into(&mut ints);
+        ints
+    }
+
+    /// Writes all the elements into `buf` in their original order,
+    /// without popping, so one buffer can be reused across calls.
+    ///
+    /// `buf` is cleared first, overwriting its prior contents, and
+    /// only grows when it cannot fit all the elements.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[0_u8, 1, 2, 3]);
+    ///
+    /// let mut buf = vec![3_u8; 10];
+    /// big_int.peek_all_into(&mut buf);
+    ///
+    /// assert!(buf == vec![0, 1, 2, 3]);
+    /// ```
+    pub fn peek_all_into<T>(&self, buf: &mut Vec<T>)
+    where
+        T: From<u8> + Copy,
+    {
+        buf.clear();
+        buf.reserve(self.total_len());
+        for codec in self.0.iter() {
+            codec.decode_into(buf);
+        }
+    }
+
+    /// Returns the elements within `range` in their original order,
+    /// decoding only the blocks which overlap it.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[0_u8, 1, 2, 3, 2]);
+    ///
+    /// assert!(big_int.get_range::<u8>(1..4) == vec![1, 2, 3]);
+    /// ```
+    /// # Panics
+    ///
+    /// This method may panic if the range is decreasing or ends out
+    /// of bounds.
+    pub fn get_range<T>(&self, range: Range<usize>) -> Vec<T>
+    where
+        T: From<u8> + Copy,
+    {
+        let len = self.total_len();
+        assert!(
+            range.start <= range.end && range.end <= len,
+            "get_range: range {:?} out of bounds (size={})",
+            range,
+            len
+        );
+
+        let mut ints = Vec::with_capacity(range.len());
+        for index in range.start / 64..range.end.div_ceil(64) {
+            let block = &self.0[index];
+            let start = range.start.saturating_sub(index * 64);
+            let end = (range.end - index * 64).min(block.size);
+            ints.extend((start..end).map(|offset| block.peek_at::<T>(offset)));
+        }
+
+        ints
+    }
+
+    /// Peeks at every requested index, in the given order, without
+    /// popping any value out of `Base4Int`.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[0_u8, 1, 2, 3]);
+    ///
+    /// assert!(big_int.batch_peek(&[3, 0, 3]) == vec![3, 0, 3]);
+    /// ```
+    /// # Panics
+    ///
+    /// This method may panic if any of the indices is out of bounds.
+    pub fn batch_peek(&self, indices: &[usize]) -> Vec<u8> {
+        let len = self.total_len();
+        indices
+            .iter()
+            .map(|index| {
+                assert!(
+                    *index < len,
+                    "peek_at: index {} out of bounds (size={})",
+                    index,
+                    len
+                );
+                self.0[index / 64].peek_at(index % 64)
+            })
+            .collect()
+    }
+
+    /// Copies the digits into a fixed size array when there are
+    /// exactly `N` of them.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[0_u8, 1, 2]);
+    ///
+    /// assert!(big_int.try_into_array::<3>() == Some([0, 1, 2]));
+    /// assert!(big_int.try_into_array::<4>() == None);
+    /// ```
+    /// Returns `None` if the number of elements is not `N`.
+    pub fn try_into_array<const N: usize>(&self) -> Option<[u8; N]> {
+        if self.total_len() != N {
+            return None;
+        }
+
+        let mut digits = [0; N];
+        digits
+            .iter_mut()
+            .zip(self.digits())
+            .for_each(|(slot, digit)| *slot = digit);
+
+        Some(digits)
+    }
+
+    /// Returns an iterator of mutable handles over every digit, in
+    /// the original order in which they were packed.
+    ///
+    /// Writing with [DigitMut::set] updates the packed block in place.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[0_u8, 1, 2, 3]);
+    ///
+    /// for mut digit in big_int.iter_mut() {
+    ///     let complement = 3 - *digit;
+    ///     digit.set(complement);
+    /// }
+    ///
+    /// assert!(big_int.peek_all::<u8>() == vec![3, 2, 1, 0]);
+    /// ```
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = DigitMut<'_>> {
+        self.0.iter_mut().flat_map(|codec| {
+            let size = codec.size;
+            let packed = Cell::from_mut(&mut codec.packed);
+            (0..size).map(move |index| DigitMut::new(packed, 2 * (size - index - 1)))
+        })
+    }
+
+    /// Reverses the order of all the digits in place.
+    ///
+    /// Blocks are reversed one at a time with bit-parallel word
+    /// operations and re-packed in the opposite order, so every
+    /// block except the last one stays full.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[0_u8, 1, 2, 3, 3]);
+    /// big_int.reverse();
+    ///
+    /// assert!(big_int.peek_all::<u8>() == vec![3, 3, 2, 1, 0]);
+    /// ```
+    pub fn reverse(&mut self) {
+        self.rebuild_reversed(false);
+    }
+
+    /// Returns an iterator over every overlapping window of `n`
+    /// consecutive digits, in order, each packed into a [Base4].
+    ///
+    /// Windows may span two blocks. The iterator is empty when `n` is
+    /// larger than the number of elements.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[0_u8, 1, 2, 3]);
+    ///
+    /// let windows: Vec<Vec<u8>> = big_int.windows(3).map(|w| w.peek_all()).collect();
+    ///
+    /// assert!(windows == vec![vec![0, 1, 2], vec![1, 2, 3]]);
+    /// ```
+    /// # Panics
+    ///
+    /// This method may panic if `n` is zero or larger than 64,
+    /// whatever the number of elements.
+    pub fn windows(&self, n: usize) -> Windows<'_> {
+        assert!(
+            (1..=64).contains(&n),
+            "window length {} out of range 1..=64",
+            n
+        );
+
+        Windows::new(self, n)
+    }
+
+    /// Reverses the order of the digits within `range`, leaving all
+    /// the other digits in place.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[0_u8, 1, 2, 3, 0]);
+    /// big_int.reverse_range(1..4);
+    ///
+    /// assert!(big_int.peek_all::<u8>() == vec![0, 3, 2, 1, 0]);
+    /// ```
+    /// # Panics
+    ///
+    /// This method may panic if the range is decreasing or ends out
+    /// of bounds.
+    pub fn reverse_range(&mut self, range: Range<usize>) {
+        let len = self.total_len();
+        assert!(
+            range.start <= range.end && range.end <= len,
+            "reverse_range: range {:?} out of bounds (size={})",
+            range,
+            len
+        );
+
+        let (mut low, mut high) = (range.start, range.end);
+        while low + 1 < high {
+            high -= 1;
+            let (a, b) = (
+                self.0[low / 64].peek_at::<u8>(low % 64),
+                self.0[high / 64].peek_at::<u8>(high % 64),
+            );
+            self.0[low / 64].write_at(low % 64, b);
+            self.0[high / 64].write_at(high % 64, a);
+            low += 1;
+        }
+    }
+
+    /// Returns the first pushed digit, which is the most significant
+    /// one, without popping it.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// assert!(big_int.first() == None);
+    ///
+    /// big_int.push_all(&[2_u8, 1, 3]);
+    /// assert!(big_int.first() == Some(2));
+    /// ```
+    /// Returns `None` if there are no elements.
+    pub fn first(&self) -> Option<u8> {
+        self.front()
+    }
+
+    /// Returns the most recently pushed digit without popping it.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// assert!(big_int.last() == None);
+    ///
+    /// big_int.push_all(&[2_u8, 1, 3]);
+    /// assert!(big_int.last() == Some(3));
+    /// ```
+    /// Returns `None` if there are no elements.
+    pub fn last(&self) -> Option<u8> {
+        self.back()
+    }
+
+    /// Returns the first inserted element, which is the first one
+    /// [Base4Int::pop_all] yields, without popping it.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// assert!(big_int.front::<u8>() == None);
+    ///
+    /// big_int.push_all(&[2_u8, 1, 3]);
+    /// assert!(big_int.front::<u64>() == Some(2));
+    /// ```
+    /// Returns `None` if there are no elements.
+    pub fn front<T>(&self) -> Option<T>
+    where
+        T: From<u8> + Copy,
+    {
+        self.0.front().map(|codec| codec.peek_at(0))
+    }
+
+    /// Returns the last inserted element without popping it.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// assert!(big_int.back::<u8>() == None);
+    ///
+    /// big_int.push_all(&[2_u8, 1, 3]);
+    /// assert!(big_int.back::<u64>() == Some(3));
+    /// ```
+    /// Returns `None` if there are no elements.
+    pub fn back<T>(&self) -> Option<T>
+    where
+        T: From<u8> + Copy,
+    {
+        self.0
+            .back()
+            .map(|codec| T::from((codec.packed & 0b11) as u8))
+    }
+
+    /// Packs all the digits into a single [Base4] block, which is
+    /// possible when there are at most 64 of them.
+    ///
+    /// This is the inverse of `Base4Int::from(block)`.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[1_u8, 2, 3]);
+    ///
+    /// let block = big_int.collapse_to_block().unwrap();
+    ///
+    /// assert!(block.peek_all::<u8>() == vec![1, 2, 3]);
+    /// ```
+    /// Returns `None` if there are more than 64 elements.
+    pub fn collapse_to_block(&self) -> Option<Base4> {
+        match self.0.len() {
+            0 => Some(Base4::new()),
+            1 => Some(self.0[0].clone()),
+            _ => None,
+        }
+    }
+
+    /// Returns the numeric value of the digits, read as a base4
+    /// number whose first digit is the most significant one.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[1_u8, 2, 3]);
+    ///
+    /// assert!(big_int.try_to_u128() == Some(27));
+    /// ```
+    /// Returns `None` if there are more than 64 elements, as the value
+    /// would not fit into a `u128`.
+    pub fn try_to_u128(&self) -> Option<u128> {
+        self.collapse_to_block().map(|block| block.to_u128())
+    }
+
+    /// Returns `true` if there are no elements, without summing
+    /// the block sizes.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// assert!(big_int.is_empty());
+    ///
+    /// big_int.push(1_u8);
+    /// assert!(!big_int.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the number of all the elements packed inside.
+    pub fn total_len(&self) -> usize {
+        self.0.iter().map(|block| block.size).sum()
+    }
+
+    /// Returns the number of bits used by the packed digits, two per
+    /// digit.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[1_u8; 70]);
+    ///
+    /// assert!(big_int.num_bits() == 140);
+    /// ```
+    pub fn num_bits(&self) -> usize {
+        self.total_len() * 2
+    }
+
+    /// Returns the number of bytes needed to hold the packed digits,
+    /// four per byte.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[1_u8; 70]);
+    ///
+    /// assert!(big_int.byte_len() == 18);
+    /// ```
+    pub fn byte_len(&self) -> usize {
+        self.num_bits().div_ceil(8)
     }
 
     /// Returns the number of [Base4] blocks.
     pub fn total_blocks(&self) -> usize {
         self.0.len()
     }
+
+    /// Returns a [fmt::Debug] view of the raw blocks, showing their
+    /// sizes and packed words, unlike the digit-oriented `Debug`
+    /// implementation of `Base4Int`.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[1_u8, 2, 3]);
+    ///
+    /// assert!(format!("{:?}", big_int.debug_raw()) == "[Base4 { size: 3, packed: 27 }]");
+    /// ```
+    pub fn debug_raw(&self) -> impl fmt::Debug + '_ {
+        &self.0
+    }
+
+    /// Returns a borrowing iterator over the underlying [Base4]
+    /// blocks, in order.
+    ///
+    /// Every block except the last one holds exactly 64 digits.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[2_u8; 130]);
+    ///
+    /// let sizes: Vec<usize> = big_int.blocks().map(|block| block.len()).collect();
+    ///
+    /// assert!(sizes == vec![64, 64, 2]);
+    /// ```
+    pub fn blocks(&self) -> impl Iterator<Item = &Base4> {
+        self.0.iter()
+    }
+
+    /// Returns an iterator over the [Base4] blocks, each paired with
+    /// the logical index of its first digit.
+    ///
+    /// Every block except the last one is full, so offsets advance by
+    /// 64 digits.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[2_u8; 130]);
+    ///
+    /// let offsets: Vec<usize> = big_int
+    ///     .iter_blocks_with_offset()
+    ///     .map(|(offset, _)| offset)
+    ///     .collect();
+    ///
+    /// assert!(offsets == vec![0, 64, 128]);
+    /// ```
+    pub fn iter_blocks_with_offset(&self) -> impl Iterator<Item = (usize, &Base4)> {
+        self.0
+            .iter()
+            .enumerate()
+            .map(|(block_idx, codec)| (block_idx * 64, codec))
+    }
+
+    /// Folds over the [Base4] blocks in order, short-circuiting on
+    /// the first `Err` returned by `f`.
+    ///
+    /// Blocks are visited without decoding their digits, which makes
+    /// this suitable for fast block-level scans that can stop early.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[1_u8; 100]);
+    ///
+    /// let digits = big_int.try_fold_blocks(0, |acc, block| {
+    ///     Ok::<_, ()>(acc + block.peek_all::<u8>().len())
+    /// });
+    ///
+    /// assert!(digits == Ok(100));
+    /// ```
+    pub fn try_fold_blocks<B, E, F>(&self, init: B, f: F) -> Result<B, E>
+    where
+        F: FnMut(B, &Base4) -> Result<B, E>,
+    {
+        self.0.iter().try_fold(init, f)
+    }
+
+    /// Lazily decodes all the digits in their original order.
+    fn digits(&self) -> impl Iterator<Item = u8> + '_ {
+        self.0
+            .iter()
+            .flat_map(|codec| (0..codec.size).map(|index| codec.peek_at(index)))
+    }
+
+    /// Re-packs the blocks in the opposite order, reversing the digits
+    /// of each one and, if `complement` is set, mapping every digit `d`
+    /// to `3 - d`, so every block except the last one stays full.
+    fn rebuild_reversed(&mut self, complement: bool) {
+        let capacity = self.0.len();
+        let blocks = core::mem::replace(&mut self.0, Base4Blocks::with_capacity(capacity));
+
+        for mut block in blocks.into_iter().rev() {
+            block.reverse_digits();
+            if complement {
+                block.packed ^= digit_mask(block.size);
+            }
+            self.push_block(block);
+        }
+    }
+
+    /// Appends `count` copies of `digit`, which must be within base4
+    /// bounds, a whole block at a time.
+    fn push_repeated(&mut self, digit: u8, count: usize) {
+        let pattern = LOW_BITS * digit as u128;
+        let mut remaining = count;
+
+        while remaining > 0 {
+            let size = remaining.min(64);
+            self.push_block(Base4 {
+                size,
+                packed: pattern & digit_mask(size),
+            });
+            remaining -= size;
+        }
+    }
+
+    /// Appends all the digits of `block` at the back, topping up
+    /// the last block first so that every block except the last
+    /// one stays full.
+    fn push_block(&mut self, block: Base4) {
+        if block.size == 0 {
+            return;
+        }
+
+        let last = match self.0.back_mut() {
+            Some(last) if last.size < 64 => last,
+            _ => {
+                self.0.push_back(block);
+                return;
+            }
+        };
+
+        let take = (64 - last.size).min(block.size);
+        let rest = block.size - take;
+
+        last.packed = (last.packed << (2 * take)) | (block.packed >> (2 * rest));
+        last.size += take;
+
+        if rest > 0 {
+            self.0.push_back(Base4 {
+                size: rest,
+                packed: block.packed & digit_mask(rest),
+            });
+        }
+    }
+}
+
+/// Selects the low bit of every 2-bit group.
+const LOW_BITS: u128 = 0x5555_5555_5555_5555_5555_5555_5555_5555;
+
+/// The 4 digits packed into every byte value, most significant first.
+const BYTE_DIGITS: [[u8; 4]; 256] = {
+    let mut table = [[0_u8; 4]; 256];
+    let mut byte = 0;
+    while byte < 256 {
+        let b = byte as u8;
+        table[byte] = [b >> 6, (b >> 4) & 0b11, (b >> 2) & 0b11, b & 0b11];
+        byte += 1;
+    }
+    table
+};
+
+/// Returns a mask covering the lowest `digits` 2-bit groups.
+fn digit_mask(digits: usize) -> u128 {
+    if digits >= 64 {
+        u128::MAX
+    } else {
+        (1 << (2 * digits)) - 1
+    }
+}
+
+impl From<Base4> for Base4Int {
+    fn from(block: Base4) -> Self {
+        let mut int = Self::new();
+        int.push_block(block);
+        int
+    }
+}
+
+/// Shows the number of digits and blocks along with the digits
+/// themselves, truncated with an ellipsis past 128 digits. Use
+/// [Base4Int::debug_raw] to inspect the packed blocks instead.
+///
+/// # Example
+/// ```
+/// use base4::Base4Int;
+///
+/// let mut big_int = Base4Int::new();
+/// big_int.push_all(&[0_u8, 1, 2, 3]);
+///
+/// assert!(format!("{big_int:?}") == r#"Base4Int { len: 4, blocks: 1, digits: "0123" }"#);
+/// ```
+impl fmt::Debug for Base4Int {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const SHOWN: usize = 128;
+
+        let mut digits = self
+            .digits()
+            .take(SHOWN)
+            .map(|digit| (b'0' + digit) as char)
+            .collect::<String>();
+        if self.total_len() > SHOWN {
+            digits.push('…');
+        }
+
+        f.debug_struct("Base4Int")
+            .field("len", &self.total_len())
+            .field("blocks", &self.total_blocks())
+            .field("digits", &digits)
+            .finish()
+    }
+}
+
+/// Packs the elements in iteration order, so the first element
+/// yielded becomes the first digit.
+///
+/// # Example
+/// ```
+/// use base4::Base4Int;
+///
+/// let big_int = (0..6).map(|i| (i % 4) as u8).collect::<Base4Int>();
+///
+/// assert!(big_int.peek_all::<u8>() == vec![0, 1, 2, 3, 0, 1]);
+/// ```
+/// # Panics
+///
+/// Collecting panics, just like [Base4Int::push], if any of the
+/// elements is not within base4 bounds.
+impl<T> FromIterator<T> for Base4Int
+where
+    T: Into<u128> + Copy,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut int = Self::new();
+        int.extend(iter);
+        int
+    }
+}
+
+/// Appends the elements in iteration order, filling the last block
+/// up to 64 digits before allocating a new one.
+///
+/// # Example
+/// ```
+/// use base4::Base4Int;
+///
+/// let mut big_int = Base4Int::new();
+/// big_int.push_all(&[0_u8, 1]);
+/// big_int.extend([2_u8, 3]);
+///
+/// assert!(big_int.peek_all::<u8>() == vec![0, 1, 2, 3]);
+/// ```
+/// # Panics
+///
+/// Extending panics, just like [Base4Int::push], if any of the
+/// elements is not within base4 bounds.
+impl<T> Extend<T> for Base4Int
+where
+    T: Into<u128> + Copy,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for integer in iter {
+            self.push(integer);
+        }
+    }
+}
+
+/// Renders the digits as ASCII characters `'0'..='3'` in their
+/// original order, with no separators.
+///
+/// # Example
+/// ```
+/// use base4::Base4Int;
+///
+/// let mut big_int = Base4Int::new();
+/// big_int.push_all(&[0_u8, 1, 2, 3, 2, 1, 0]);
+///
+/// assert!(big_int.to_string() == "0123210");
+/// assert!(Base4Int::new().to_string() == "");
+/// ```
+impl fmt::Display for Base4Int {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0
+            .iter()
+            .try_for_each(|codec| fmt::Display::fmt(codec, f))
+    }
+}
+
+/// Parses a string of `'0'..='3'` characters, packing them in order.
+///
+/// Whitespace is not skipped: like any other character outside of
+/// that range, it is rejected with [Base4Error::InvalidDigit], whose
+/// position counts characters rather than bytes.
+///
+/// # Example
+/// ```
+/// use base4::{Base4Error, Base4Int};
+///
+/// let big_int = "0123210".parse::<Base4Int>().unwrap();
+/// assert!(big_int.peek_all::<u8>() == vec![0, 1, 2, 3, 2, 1, 0]);
+///
+/// let err = "01 2".parse::<Base4Int>().unwrap_err();
+/// assert!(err == Base4Error::InvalidDigit { ch: ' ', position: 2 });
+/// ```
+impl FromStr for Base4Int {
+    type Err = Base4Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut int = Self::new();
+        for (position, ch) in s.chars().enumerate() {
+            match ch {
+                '0'..='3' => int.push(ch as u8 - b'0'),
+                _ => return Err(Base4Error::InvalidDigit { ch, position }),
+            }
+        }
+
+        Ok(int)
+    }
 }
 
 impl Index<usize> for Base4Int {
@@ -203,7 +1679,7 @@ impl Index<usize> for Base4Int {
 /// difference between these two types is that Base4 can never pack
 /// slices larger than 64 elements. So if you want to store recursively
 /// large arrays of base4, then use [Base4Int].
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Base4 {
     /// Keeps the current size of block in terms of
     /// number of elements.
@@ -213,17 +1689,103 @@ pub struct Base4 {
     packed: u128,
 }
 
+/// Renders the digits as ASCII characters `'0'..='3'` in their
+/// original order, with no separators.
+///
+/// # Example
+/// ```
+/// use base4::Base4;
+///
+/// let mut codec = Base4::new();
+/// codec.push_all(&[3_u8, 0, 2]);
+///
+/// assert!(codec.to_string() == "302");
+/// ```
+impl fmt::Display for Base4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.iter()
+            .try_for_each(|digit| fmt::Write::write_char(f, (b'0' + digit) as char))
+    }
+}
+
 impl Default for Base4 {
     fn default() -> Self {
         Self::new()
     }
-}
+}
+
+impl Base4 {
+    /// Creates a new instance of [Base4] block with default
+    /// size and container.
+    pub fn new() -> Self {
+        Base4 { size: 0, packed: 0 }
+    }
+
+    /// Returns the number of elements packed inside the block.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use base4::Base4;
+    ///
+    /// let mut codec = Base4::new();
+    /// codec.push_all(&[1_u8, 2, 3]);
+    ///
+    /// assert!(codec.len() == 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the number of bits used by the packed digits, two per
+    /// digit.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use base4::Base4;
+    ///
+    /// let mut codec = Base4::new();
+    /// codec.push_all(&[1_u8, 2, 3]);
+    ///
+    /// assert!(codec.num_bits() == 6);
+    /// ```
+    pub fn num_bits(&self) -> usize {
+        self.size * 2
+    }
+
+    /// Returns the number of bytes needed to hold the packed digits,
+    /// four per byte.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use base4::Base4;
+    ///
+    /// let mut codec = Base4::new();
+    /// codec.push_all(&[1_u8, 2, 3, 0, 1]);
+    ///
+    /// assert!(codec.byte_len() == 2);
+    /// ```
+    pub fn byte_len(&self) -> usize {
+        self.num_bits().div_ceil(8)
+    }
 
-impl Base4 {
-    /// Creates a new instance of [Base4] block with default
-    /// size and container.
-    pub fn new() -> Self {
-        Base4 { size: 0, packed: 0 }
+    /// Returns `true` if the block holds no elements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use base4::Base4;
+    ///
+    /// let mut codec = Base4::new();
+    /// assert!(codec.is_empty());
+    ///
+    /// codec.push(1u8);
+    /// assert!(!codec.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
     }
 
     /// Packs a single element at the back. This may fail if
@@ -239,24 +1801,55 @@ impl Base4 {
     /// assert!(codec.push(1u8));
     /// assert!(!codec.push(4u8));
     /// ```
-    /// Returns `true` if the element is inserted else false.
+    /// Returns `true` if the element is inserted else false, use
+    /// [Base4::try_push] to tell why it was not.
     pub fn push<T>(&mut self, integer: T) -> bool
     where
         T: Into<u128> + Copy,
     {
-        if integer.into() >= 4 || self.size == 64 {
-            return false;
+        self.try_push(integer).is_ok()
+    }
+
+    /// Packs a single element at the back, reporting why it could
+    /// not be packed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use base4::{Base4, Base4PushError};
+    ///
+    /// let mut codec = Base4::new();
+    /// assert!(codec.try_push(4_u8) == Err(Base4PushError::OutOfRange { value: 4 }));
+    ///
+    /// codec.push_all(&[1_u8; 64]);
+    /// assert!(codec.try_push(1_u8) == Err(Base4PushError::Full));
+    /// ```
+    /// Returns [Base4PushError::Full] if the block already holds 64
+    /// elements, or [Base4PushError::OutOfRange] if the integer is not
+    /// within base4 bounds.
+    pub fn try_push<T>(&mut self, integer: T) -> Result<(), Base4PushError>
+    where
+        T: Into<u128> + Copy,
+    {
+        let value = integer.into();
+        if value >= 4 {
+            return Err(Base4PushError::OutOfRange { value });
+        }
+        if self.size == 64 {
+            return Err(Base4PushError::Full);
         }
-        self.size += 1;
-        self.packed = (self.packed << 2) | integer.into();
 
-        true
+        self.size += 1;
+        self.packed = (self.packed << 2) | value;
+        Ok(())
     }
 
     /// Packs a slice of integers.
     ///
-    /// This may fail if the slice is larger than 64 or if any
-    /// integer in the slice is greater than the base4 bounds.
+    /// This may fail if the slice does not fit into the remaining
+    /// capacity or if any integer in the slice is greater than the
+    /// base4 bounds. The whole slice is validated before packing, so
+    /// a failed call leaves the block untouched.
     ///
     /// # Example
     ///
@@ -265,10 +1858,11 @@ impl Base4 {
     ///
     /// let mut codec = Base4::new();
     ///
-    /// let integers = vec![3_u8;64];
+    /// assert!(codec.push_all(&[0_u8, 1]));
+    /// assert!(!codec.push_all(&[2_u8, 4, 3]));
+    /// assert!(codec.peek_all::<u8>() == vec![0, 1]);
     ///
-    /// assert!(codec.push_all(&integers));
-    /// assert!(!codec.push(4_u8));
+    /// assert!(codec.push_all(&[3_u8; 62]));
     /// assert!(!codec.push(2_u8));
     /// ```
     /// Returns `true` if it packs every element of slice.
@@ -276,17 +1870,13 @@ impl Base4 {
     where
         T: Into<u128> + Copy,
     {
-        if ints.len() > 64 {
+        if self.size + ints.len() > 64 || ints.iter().any(|integer| (*integer).into() >= 4) {
             return false;
         }
 
         for integer in ints {
-            if !self.push(*integer) {
-                self.size = 0;
-                self.packed = 0;
-
-                return false;
-            }
+            self.size += 1;
+            self.packed = (self.packed << 2) | (*integer).into();
         }
         true
     }
@@ -311,7 +1901,7 @@ impl Base4 {
     /// ```
     /// Returns none if the block is already empty.
     pub fn pop(&mut self) -> Option<u8> {
-        if self.size <= 0 {
+        if self.is_empty() {
             return None;
         }
 
@@ -346,18 +1936,31 @@ impl Base4 {
     where
         T: From<u8> + Copy,
     {
-        if self.size <= 0 {
-            return vec![];
-        }
-
         let mut ints = Vec::with_capacity(self.size);
-        while let Some(value) = self.pop() {
-            ints.push(T::from(value));
-        }
-        ints.reverse();
+        self.decode_into(&mut ints);
+        self.clear();
         ints
     }
 
+    /// Removes all the elements, leaving the block in its default
+    /// state.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use base4::Base4;
+    ///
+    /// let mut codec = Base4::new();
+    /// codec.push_all(&[1_u8, 2, 3]);
+    /// codec.clear();
+    ///
+    /// assert!(codec.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self.size = 0;
+        self.packed = 0;
+    }
+
     /// Peeks at a specific element by index according to the
     /// original list from which the element were inserted without
     /// popping the value out of `Base4` buffer.
@@ -393,6 +1996,42 @@ impl Base4 {
         T::from(((self.packed >> shift_pos) & 0b11) as u8)
     }
 
+    /// Overwrites the element at `index`, according to the original
+    /// list from which the elements were inserted.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4;
+    ///
+    /// let mut codec = Base4::new();
+    /// codec.push_all(&[0_u8, 1, 2]);
+    /// codec.set_at(2, 0_u8);
+    ///
+    /// assert!(codec.peek_all::<u8>() == vec![0, 1, 0]);
+    /// ```
+    /// # Panics
+    ///
+    /// This method may panic if the index is out of bounds or if the
+    /// value is not within base4 bounds.
+    pub fn set_at<T>(&mut self, index: usize, value: T)
+    where
+        T: Into<u128> + Copy,
+    {
+        assert!(
+            index < self.size,
+            "set_at: index {} out of bounds (size={})",
+            index,
+            self.size
+        );
+
+        let value = value.into();
+        if value >= 4 {
+            panic!("{}", Base4Error::OutOfRange { value });
+        }
+
+        self.write_at(index, value as u8);
+    }
+
     /// Returns the list of all the elements packed inside the
     /// [Base4] without popping.
     ///
@@ -419,10 +2058,356 @@ impl Base4 {
         T: From<u8> + Copy,
     {
         let mut ints = Vec::with_capacity(self.size);
-        for index in 0..self.size {
-            ints.push(self.peek_at(index));
+        self.decode_into(&mut ints);
+        ints
+    }
+
+    /// Returns the numeric value of the packed digits, read as a
+    /// base4 number whose first digit is the most significant one.
+    ///
+    /// This is also the raw packed word, its bits above the
+    /// [Base4::len] used digits always being zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use base4::Base4;
+    ///
+    /// let mut codec = Base4::new();
+    /// codec.push_all(&[1_u8, 2, 3]);
+    ///
+    /// assert!(codec.to_u128() == 0b01_10_11);
+    /// ```
+    pub fn to_u128(&self) -> u128 {
+        self.packed
+    }
+
+    /// Alias of [Base4::to_u128], returning the raw packed word.
+    pub fn as_u128(&self) -> u128 {
+        self.to_u128()
+    }
+
+    /// Formats the numeric value of the packed digits, as returned by
+    /// [Base4::to_u128], in the given radix with lower case letters.
+    ///
+    /// Leading zero digits do not change the value, so they are
+    /// dropped: `[0, 0, 1]` formats as `"1"`, and an empty block
+    /// formats as `"0"`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use base4::Base4;
+    ///
+    /// let mut codec = Base4::new();
+    /// codec.push_all(&[0_u8, 3, 3]);
+    ///
+    /// assert!(codec.to_radix_string(2) == "1111");
+    /// assert!(codec.to_radix_string(10) == "15");
+    /// assert!(codec.to_radix_string(36) == "f");
+    /// ```
+    /// # Panics
+    ///
+    /// This method may panic if `radix` is not within `2..=36`.
+    pub fn to_radix_string(&self, radix: u32) -> String {
+        assert!(
+            (2..=36).contains(&radix),
+            "radix {} out of range 2..=36",
+            radix
+        );
+
+        let mut value = self.packed;
+        let mut digits = Vec::new();
+        loop {
+            digits.push(char::from_digit((value % radix as u128) as u32, radix).unwrap());
+            value /= radix as u128;
+            if value == 0 {
+                break;
+            }
         }
 
-        ints
+        digits.iter().rev().collect()
+    }
+
+    /// Formats the numeric value of the packed digits in lower case
+    /// hexadecimal, dropping leading zeros like
+    /// [Base4::to_radix_string].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use base4::Base4;
+    ///
+    /// let mut codec = Base4::new();
+    /// codec.push_all(&[0_u8, 3, 3, 2, 1]);
+    ///
+    /// assert!(codec.to_hex() == "f9");
+    /// ```
+    pub fn to_hex(&self) -> String {
+        format!("{:x}", self.packed)
+    }
+
+    /// Rebuilds a block holding `digit_count` digits from their
+    /// numeric value, as returned by [Base4::to_u128].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use base4::Base4;
+    ///
+    /// let codec = Base4::from_u128(27, 4);
+    ///
+    /// assert!(codec.peek_all::<u8>() == vec![0, 1, 2, 3]);
+    /// ```
+    /// # Panics
+    ///
+    /// This method may panic if `digit_count` is larger than 64, or
+    /// if `value` does not fit into `digit_count` digits.
+    pub fn from_u128(value: u128, digit_count: usize) -> Base4 {
+        assert!(
+            digit_count <= 64,
+            "digit count {} out of range 0..=64",
+            digit_count
+        );
+        assert!(
+            value & !digit_mask(digit_count) == 0,
+            "value {} does not fit into {} digits",
+            value,
+            digit_count
+        );
+
+        Base4 {
+            size: digit_count,
+            packed: value,
+        }
+    }
+
+    /// Returns an iterator over the packed digits, in the original
+    /// order in which they were inserted, without allocating.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use base4::Base4;
+    ///
+    /// let mut codec = Base4::new();
+    /// codec.push_all(&[0_u8, 1, 2, 3]);
+    ///
+    /// assert!(codec.iter().collect::<Vec<_>>() == vec![0, 1, 2, 3]);
+    /// assert!(codec.iter().rev().collect::<Vec<_>>() == vec![3, 2, 1, 0]);
+    /// assert!(codec.iter().len() == 4);
+    /// ```
+    pub fn iter(&self) -> Base4Iter<'_> {
+        Base4Iter::new(self)
+    }
+
+    /// Reverses the order of the packed digits in place.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use base4::Base4;
+    ///
+    /// let mut codec = Base4::new();
+    /// codec.push_all(&[0_u8, 1, 2]);
+    /// codec.reverse();
+    ///
+    /// assert!(codec.peek_all::<u8>() == vec![2, 1, 0]);
+    /// ```
+    pub fn reverse(&mut self) {
+        self.reverse_digits();
+    }
+
+    /// Drops the `n` oldest digits, which sit at the high end of the
+    /// packed buffer, keeping the newest ones.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use base4::Base4;
+    ///
+    /// let mut codec = Base4::new();
+    /// codec.push_all(&[0_u8, 1, 2, 3]);
+    /// codec.shift_left(1);
+    ///
+    /// assert!(codec.peek_all::<u8>() == vec![1, 2, 3]);
+    /// ```
+    /// # Panics
+    ///
+    /// This method may panic if `n` is larger than the number of
+    /// packed elements.
+    pub fn shift_left(&mut self, n: usize) {
+        assert!(
+            n <= self.size,
+            "shift_left: shift {} out of bounds (size={})",
+            n,
+            self.size
+        );
+
+        self.size -= n;
+        self.packed &= digit_mask(self.size);
+    }
+
+    /// Drops the `n` newest digits, which sit at the low end of the
+    /// packed buffer, keeping the oldest ones.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use base4::Base4;
+    ///
+    /// let mut codec = Base4::new();
+    /// codec.push_all(&[0_u8, 1, 2, 3]);
+    /// codec.shift_right(1);
+    ///
+    /// assert!(codec.peek_all::<u8>() == vec![0, 1, 2]);
+    /// ```
+    /// # Panics
+    ///
+    /// This method may panic if `n` is larger than the number of
+    /// packed elements.
+    pub fn shift_right(&mut self, n: usize) {
+        assert!(
+            n <= self.size,
+            "shift_right: shift {} out of bounds (size={})",
+            n,
+            self.size
+        );
+
+        self.size -= n;
+        self.packed = self.packed.checked_shr(2 * n as u32).unwrap_or(0);
+    }
+
+    /// Combines both blocks with a per-digit bitwise AND of their
+    /// 2-bit values, which is not an arithmetic operation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use base4::Base4;
+    ///
+    /// let a = Base4::from_u128(0b01_10_11, 3);
+    /// let b = Base4::from_u128(0b11_11_01, 3);
+    ///
+    /// assert!(a.and(&b).peek_all::<u8>() == vec![1, 2, 1]);
+    /// ```
+    /// # Panics
+    ///
+    /// This method may panic if both blocks differ in size.
+    pub fn and(&self, other: &Base4) -> Base4 {
+        self.zip_packed(other, "and", |a, b| a & b)
+    }
+
+    /// Combines both blocks with a per-digit bitwise OR of their
+    /// 2-bit values, which is not an arithmetic operation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use base4::Base4;
+    ///
+    /// let a = Base4::from_u128(0b01_10_00, 3);
+    /// let b = Base4::from_u128(0b10_00_01, 3);
+    ///
+    /// assert!(a.or(&b).peek_all::<u8>() == vec![3, 2, 1]);
+    /// ```
+    /// # Panics
+    ///
+    /// This method may panic if both blocks differ in size.
+    pub fn or(&self, other: &Base4) -> Base4 {
+        self.zip_packed(other, "or", |a, b| a | b)
+    }
+
+    /// Combines both blocks with a per-digit bitwise XOR of their
+    /// 2-bit values, which is not an arithmetic operation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use base4::Base4;
+    ///
+    /// let a = Base4::from_u128(0b01_10_11, 3);
+    /// let b = Base4::from_u128(0b11_10_01, 3);
+    ///
+    /// assert!(a.xor(&b).peek_all::<u8>() == vec![2, 0, 2]);
+    /// ```
+    /// # Panics
+    ///
+    /// This method may panic if both blocks differ in size.
+    pub fn xor(&self, other: &Base4) -> Base4 {
+        self.zip_packed(other, "xor", |a, b| a ^ b)
+    }
+
+    /// Applies `f` to both packed words at once, which must hold the
+    /// same number of digits.
+    fn zip_packed<F>(&self, other: &Base4, op: &str, f: F) -> Base4
+    where
+        F: FnOnce(u128, u128) -> u128,
+    {
+        assert!(
+            self.size == other.size,
+            "{}: length mismatch ({} != {})",
+            op,
+            self.size,
+            other.size
+        );
+
+        Base4 {
+            size: self.size,
+            packed: f(self.packed, other.packed) & digit_mask(self.size),
+        }
+    }
+
+    /// Appends all the packed digits to `out` in their original
+    /// order, decoding a whole byte of 4 digits per table lookup.
+    fn decode_into<T>(&self, out: &mut Vec<T>)
+    where
+        T: From<u8> + Copy,
+    {
+        // Left-align the digits so they start at the first byte.
+        let aligned = self
+            .packed
+            .checked_shl(2 * (64 - self.size) as u32)
+            .unwrap_or(0);
+
+        let mut remaining = self.size;
+        for byte in &aligned.to_be_bytes()[..self.size.div_ceil(4)] {
+            let take = remaining.min(4);
+            out.extend(
+                BYTE_DIGITS[*byte as usize][..take]
+                    .iter()
+                    .map(|d| T::from(*d)),
+            );
+            remaining -= take;
+        }
+    }
+
+    /// Overwrites the digit at `index`, which must be in bounds.
+    fn write_at(&mut self, index: usize, digit: u8) {
+        let shift_pos = 2 * (self.size - index - 1);
+        self.packed = (self.packed & !(0b11 << shift_pos)) | (digit as u128) << shift_pos;
+    }
+
+    /// Returns a word with the low bit of every 2-bit group set where
+    /// the packed digit equals `digit`.
+    fn matches(&self, digit: u8) -> u128 {
+        let equal = !(self.packed ^ (LOW_BITS * digit as u128));
+        equal & (equal >> 1) & LOW_BITS & digit_mask(self.size)
+    }
+
+    /// Reverses the order of the packed digits using bit-parallel
+    /// swaps of the 2-bit groups.
+    fn reverse_digits(&mut self) {
+        if self.size == 0 {
+            return;
+        }
+
+        let mut x = self.packed;
+        x = ((x >> 2) & 0x3333_3333_3333_3333_3333_3333_3333_3333)
+            | ((x & 0x3333_3333_3333_3333_3333_3333_3333_3333) << 2);
+        x = ((x >> 4) & 0x0F0F_0F0F_0F0F_0F0F_0F0F_0F0F_0F0F_0F0F)
+            | ((x & 0x0F0F_0F0F_0F0F_0F0F_0F0F_0F0F_0F0F_0F0F) << 4);
+
+        self.packed = x.swap_bytes() >> (2 * (64 - self.size));
     }
 }
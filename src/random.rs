@@ -0,0 +1,29 @@
+use rand::{Rng, distr::weighted::WeightedIndex};
+
+use crate::Base4Int;
+
+impl Base4Int {
+    /// Generates `len` random digits, each digit `i` being drawn with
+    /// a probability proportional to `weights[i]`.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut rng = rand::rng();
+    /// let big_int = Base4Int::weighted_random([0.0, 1.0, 0.0, 0.0], 100, &mut rng);
+    ///
+    /// assert!(big_int.nonzero_count() == 100);
+    /// ```
+    /// # Panics
+    ///
+    /// This method may panic if any weight is negative or not finite,
+    /// or if all the weights are zero.
+    pub fn weighted_random(weights: [f64; 4], len: usize, rng: &mut impl Rng) -> Base4Int {
+        let distr = WeightedIndex::new(weights).expect("weighted_random: invalid weights");
+
+        let mut int = Base4Int::new();
+        (0..len).for_each(|_| int.push(rng.sample(&distr) as u8));
+        int
+    }
+}
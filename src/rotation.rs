@@ -0,0 +1,85 @@
+use alloc::vec;
+
+use crate::Base4Int;
+
+impl Base4Int {
+    /// Returns the lexicographically smallest rotation of the digits,
+    /// found with Booth's algorithm in linear time.
+    ///
+    /// Circularly equivalent sequences share the same canonical
+    /// rotation.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[2_u8, 0, 1, 0, 0]);
+    ///
+    /// assert!(big_int.canonical_rotation().peek_all::<u8>() == vec![0, 0, 2, 0, 1]);
+    /// ```
+    pub fn canonical_rotation(&self) -> Base4Int {
+        let digits = self.peek_all::<u8>();
+        let start = least_rotation(&digits);
+
+        Base4Int::build_from_chunks(&[&digits[start..], &digits[..start]])
+    }
+
+    /// Returns `true` if `other` is a cyclic rotation of `self`.
+    ///
+    /// Both must have the same length, in which case `other` is
+    /// searched within `self` concatenated with itself in linear time.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut a = Base4Int::new();
+    /// let mut b = Base4Int::new();
+    ///
+    /// a.push_all(&[0_u8, 1, 2, 3]);
+    /// b.push_all(&[2_u8, 3, 0, 1]);
+    ///
+    /// assert!(a.is_rotation_of(&b));
+    /// ```
+    pub fn is_rotation_of(&self, other: &Base4Int) -> bool {
+        if self.total_len() != other.total_len() {
+            return false;
+        }
+
+        let mut doubled = self.clone();
+        doubled.append(self.clone());
+
+        doubled.contains(&other.peek_all::<u8>())
+    }
+}
+
+/// Booth's algorithm, returning the start of the least rotation.
+fn least_rotation(digits: &[u8]) -> usize {
+    let n = digits.len() as isize;
+    let at = |index: isize| digits[(index % n) as usize];
+
+    let mut failure = vec![-1_isize; 2 * digits.len()];
+    let mut k = 0_isize;
+
+    for j in 1..2 * n {
+        let mut i = failure[(j - k - 1) as usize];
+        while i != -1 && at(j) != at(k + i + 1) {
+            if at(j) < at(k + i + 1) {
+                k = j - i - 1;
+            }
+            i = failure[i as usize];
+        }
+
+        if i == -1 && at(j) != at(k + i + 1) {
+            if at(j) < at(k + i + 1) {
+                k = j;
+            }
+            failure[(j - k) as usize] = -1;
+        } else {
+            failure[(j - k) as usize] = i + 1;
+        }
+    }
+
+    k as usize
+}
@@ -0,0 +1,78 @@
+use alloc::format;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error, ser::SerializeTuple};
+
+use crate::{Base4, Base4Blocks, Base4Int, digit_mask};
+
+/// Serializes the block as a `(size, packed)` pair.
+impl Serialize for Base4 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(2)?;
+        tuple.serialize_element(&self.size)?;
+        tuple.serialize_element(&self.packed)?;
+        tuple.end()
+    }
+}
+
+/// Deserializes a `(size, packed)` pair, rejecting sizes above 64 and
+/// packed bits beyond `size` digits.
+impl<'de> Deserialize<'de> for Base4 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (size, packed) = <(usize, u128)>::deserialize(deserializer)?;
+
+        if size > 64 {
+            return Err(D::Error::custom(format!(
+                "block size {size} out of range 0..=64"
+            )));
+        }
+        if packed & !digit_mask(size) != 0 {
+            return Err(D::Error::custom(format!(
+                "packed value holds more than {size} digits"
+            )));
+        }
+
+        Ok(Base4 { size, packed })
+    }
+}
+
+/// Serializes the integer as the sequence of its blocks, so the block
+/// boundaries survive a round-trip.
+///
+/// # Example
+/// ```
+/// use base4::Base4Int;
+///
+/// let mut big_int = Base4Int::new();
+/// big_int.push_all(&[0_u8, 1, 2, 3]);
+///
+/// let json = serde_json::to_string(&big_int).unwrap();
+/// assert!(json == "[[4,27]]");
+///
+/// let decoded: Base4Int = serde_json::from_str(&json).unwrap();
+/// assert!(decoded.peek_all::<u8>() == vec![0, 1, 2, 3]);
+/// ```
+impl Serialize for Base4Int {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.iter())
+    }
+}
+
+/// Deserializes a sequence of blocks, rejecting empty blocks and
+/// partial blocks anywhere but at the end.
+impl<'de> Deserialize<'de> for Base4Int {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let blocks = Base4Blocks::deserialize(deserializer)?;
+
+        let last = blocks.len().saturating_sub(1);
+        for (index, block) in blocks.iter().enumerate() {
+            if block.size == 0 || (index < last && block.size < 64) {
+                return Err(D::Error::custom(format!(
+                    "block {index} holds {} digits",
+                    block.size
+                )));
+            }
+        }
+
+        Ok(Base4Int(blocks))
+    }
+}
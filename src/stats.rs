@@ -0,0 +1,224 @@
+use alloc::vec::Vec;
+
+use crate::{Base4, Base4Int, LOW_BITS, digit_mask};
+
+impl Base4Int {
+    /// Returns the number of digits which are not zero.
+    ///
+    /// Every block is counted with a single popcount over its
+    /// packed word.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[0_u8, 1, 0, 3, 2]);
+    ///
+    /// assert!(big_int.nonzero_count() == 3);
+    /// ```
+    pub fn nonzero_count(&self) -> usize {
+        self.0
+            .iter()
+            .map(|block| {
+                let nonzero = (block.packed | (block.packed >> 1)) & LOW_BITS;
+                (nonzero & digit_mask(block.size)).count_ones() as usize
+            })
+            .sum()
+    }
+
+    /// Returns how many times `digit` appears, an alias of
+    /// [Base4Int::count] for popcount-style density metrics.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[0_u8, 1, 0, 3, 2]);
+    ///
+    /// assert!(big_int.popcount_digit(0_u8) == 2);
+    /// assert!(big_int.popcount_digit(0_u8) + big_int.nonzero_count() == 5);
+    /// ```
+    pub fn popcount_digit<T>(&self, digit: T) -> usize
+    where
+        T: Into<u128> + Copy,
+    {
+        self.count(digit)
+    }
+
+    /// Returns how many times `digit` appears, counting every block
+    /// with a single popcount over its packed word.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[0_u8, 1, 1, 3, 2]);
+    ///
+    /// assert!(big_int.count(1_u8) == 2);
+    /// assert!(big_int.count(7_u8) == 0);
+    /// ```
+    /// Values outside of base4 bounds never appear, so they are
+    /// counted as zero.
+    pub fn count<T>(&self, digit: T) -> usize
+    where
+        T: Into<u128> + Copy,
+    {
+        self.0.iter().map(|block| block.count(digit)).sum()
+    }
+
+    /// Returns how many times each of the four digits appears, in a
+    /// single pass over the blocks.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[0_u8, 1, 1, 3, 2]);
+    ///
+    /// assert!(big_int.histogram() == [1, 2, 1, 1]);
+    /// ```
+    pub fn histogram(&self) -> [usize; 4] {
+        self.0.iter().fold([0; 4], |mut histogram, block| {
+            let counts = block.histogram();
+            (0..4).for_each(|digit| histogram[digit] += counts[digit]);
+            histogram
+        })
+    }
+
+    /// Returns the fraction of the allocated digit slots actually in
+    /// use, `total_len() / (total_blocks() * 64)`.
+    ///
+    /// Only the last block can be partially filled, so this gets
+    /// closer to `1.0` as the integer grows. An empty integer wastes
+    /// no slot and reports `1.0`.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[1_u8; 96]);
+    ///
+    /// assert!(big_int.pack_efficiency() == 0.75);
+    /// ```
+    pub fn pack_efficiency(&self) -> f64 {
+        if self.is_empty() {
+            return 1.0;
+        }
+
+        self.total_len() as f64 / (self.total_blocks() * 64) as f64
+    }
+
+    /// Returns `true` if both integers have the same sequence of run
+    /// lengths, regardless of which digits form the runs.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut a = Base4Int::new();
+    /// let mut b = Base4Int::new();
+    ///
+    /// a.push_all(&[0_u8, 0, 1]);
+    /// b.push_all(&[2_u8, 2, 3]);
+    ///
+    /// assert!(a.same_run_structure(&b));
+    /// ```
+    pub fn same_run_structure(&self, other: &Base4Int) -> bool {
+        self.total_len() == other.total_len() && self.run_lengths() == other.run_lengths()
+    }
+
+    /// Returns the histogram of the four digits within every
+    /// consecutive, non-overlapping segment of `seg_len` digits.
+    /// The last segment may be shorter.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[0_u8, 1, 1, 3, 3]);
+    ///
+    /// let histograms = big_int.segment_histogram(2);
+    ///
+    /// assert!(histograms == vec![[1, 1, 0, 0], [0, 1, 0, 1], [0, 0, 0, 1]]);
+    /// ```
+    /// # Panics
+    ///
+    /// This method may panic if `seg_len` is zero.
+    pub fn segment_histogram(&self, seg_len: usize) -> Vec<[usize; 4]> {
+        assert!(seg_len > 0, "segment_histogram: seg_len must be non-zero");
+
+        let mut histograms = Vec::with_capacity(self.total_len().div_ceil(seg_len));
+        for (index, digit) in self.digits().enumerate() {
+            if index % seg_len == 0 {
+                histograms.push([0; 4]);
+            }
+            histograms.last_mut().unwrap()[digit as usize] += 1;
+        }
+
+        histograms
+    }
+
+    /// Returns the lengths of the consecutive runs of equal digits.
+    fn run_lengths(&self) -> Vec<usize> {
+        let mut runs = Vec::new();
+        let mut previous = None;
+
+        for digit in self.peek_all::<u8>() {
+            match runs.last_mut() {
+                Some(run) if previous == Some(digit) => *run += 1,
+                _ => runs.push(1),
+            }
+            previous = Some(digit);
+        }
+
+        runs
+    }
+}
+
+impl Base4 {
+    /// Returns how many times `digit` appears, using a branch-free
+    /// popcount over the 2-bit groups of the packed word.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4;
+    ///
+    /// let mut codec = Base4::new();
+    /// codec.push_all(&[0_u8, 1, 1, 3, 2]);
+    ///
+    /// assert!(codec.count(1_u8) == 2);
+    /// ```
+    /// Values outside of base4 bounds never appear, so they are
+    /// counted as zero.
+    pub fn count<T>(&self, digit: T) -> usize
+    where
+        T: Into<u128> + Copy,
+    {
+        match digit.into() {
+            digit @ 0..=3 => self.matches(digit as u8).count_ones() as usize,
+            _ => 0,
+        }
+    }
+
+    /// Returns how many times each of the four digits appears.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4;
+    ///
+    /// let mut codec = Base4::new();
+    /// codec.push_all(&[0_u8, 1, 1, 3, 2]);
+    ///
+    /// assert!(codec.histogram() == [1, 2, 1, 1]);
+    /// ```
+    pub fn histogram(&self) -> [usize; 4] {
+        let [ones, twos, threes] = [1, 2, 3].map(|digit| self.count(digit as u8));
+        [self.size - ones - twos - threes, ones, twos, threes]
+    }
+}
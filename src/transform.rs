@@ -0,0 +1,158 @@
+use alloc::{vec, vec::Vec};
+
+use crate::{Base4, Base4Int};
+
+impl Base4Int {
+    /// Splits the digits at every occurrence of `delimiter`, which is
+    /// left out of the fragments.
+    ///
+    /// Like `str::split`, consecutive delimiters produce empty
+    /// fragments, and `n` delimiters always produce `n + 1` fragments.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[0_u8, 1, 3, 2, 3, 0]);
+    ///
+    /// let parts: Vec<Vec<u8>> = big_int
+    ///     .split_on(3)
+    ///     .iter()
+    ///     .map(|part| part.peek_all())
+    ///     .collect();
+    ///
+    /// assert!(parts == vec![vec![0, 1], vec![2], vec![0]]);
+    /// ```
+    /// # Panics
+    ///
+    /// This method may panic if `delimiter` is not within base4 bounds.
+    pub fn split_on(&self, delimiter: u8) -> Vec<Base4Int> {
+        assert!(
+            delimiter < 4,
+            "Base4Int only accepts value bounded within 0..=3"
+        );
+
+        let mut parts = vec![Base4Int::new()];
+        for digit in self.digits() {
+            if digit == delimiter {
+                parts.push(Base4Int::new());
+            } else {
+                parts.last_mut().unwrap().push(digit);
+            }
+        }
+
+        parts
+    }
+
+    /// Replaces every non-overlapping occurrence of the `old` digit
+    /// pattern, scanning from the front, with the `new` digits and
+    /// re-packs the result. Returns the number of replacements.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[3_u8, 3, 3, 1, 3, 3]);
+    ///
+    /// assert!(big_int.replace_subsequence(&[3, 3], &[0]) == 2);
+    /// assert!(big_int.peek_all::<u8>() == vec![0, 3, 1, 0]);
+    /// ```
+    /// # Panics
+    ///
+    /// This method may panic if `old` is empty or if any digit of
+    /// `new` is not within base4 bounds.
+    pub fn replace_subsequence(&mut self, old: &[u8], new: &[u8]) -> usize {
+        assert!(!old.is_empty(), "replace_subsequence: empty pattern");
+        assert!(
+            new.iter().all(|digit| *digit < 4),
+            "Base4Int only accepts value bounded within 0..=3"
+        );
+
+        let digits = self.peek_all::<u8>();
+        let mut replaced = Vec::with_capacity(digits.len());
+        let mut count = 0;
+        let mut index = 0;
+
+        while index < digits.len() {
+            if digits[index..].starts_with(old) {
+                replaced.extend_from_slice(new);
+                index += old.len();
+                count += 1;
+            } else {
+                replaced.push(digits[index]);
+                index += 1;
+            }
+        }
+
+        if count > 0 {
+            *self = Base4Int::build_from_chunks(&[&replaced]);
+        }
+
+        count
+    }
+
+    /// Keeps only the digits for which `f` returns `true`, preserving
+    /// their order, and re-packs them into full blocks.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[0_u8, 1, 0, 3, 2]);
+    /// big_int.retain(|digit| digit != 0);
+    ///
+    /// assert!(big_int.peek_all::<u8>() == vec![1, 3, 2]);
+    /// ```
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(u8) -> bool,
+    {
+        let kept = self.digits().filter(|digit| f(*digit)).collect();
+        *self = kept;
+    }
+
+    /// Returns a new `Base4Int` holding `f` applied to every digit,
+    /// with the same length and block layout.
+    ///
+    /// # Example
+    /// ```
+    /// use base4::Base4Int;
+    ///
+    /// let mut big_int = Base4Int::new();
+    /// big_int.push_all(&[0_u8, 1, 2, 3]);
+    ///
+    /// let complement = big_int.map_digits(|digit| 3 - digit);
+    ///
+    /// assert!(complement.peek_all::<u8>() == vec![3, 2, 1, 0]);
+    /// ```
+    /// # Panics
+    ///
+    /// This method may panic if `f` returns a value which is not
+    /// within base4 bounds.
+    pub fn map_digits<F>(&self, mut f: F) -> Base4Int
+    where
+        F: FnMut(u8) -> u8,
+    {
+        let blocks = self
+            .0
+            .iter()
+            .map(|block| {
+                let mut mapped = Base4::new();
+                for digit in block.iter() {
+                    let digit = f(digit);
+                    assert!(
+                        digit < 4,
+                        "Base4Int only accepts value bounded within 0..=3"
+                    );
+                    mapped.push(digit);
+                }
+                mapped
+            })
+            .collect();
+
+        Base4Int(blocks)
+    }
+}
@@ -3,7 +3,7 @@ use rand::{
     distr::{Uniform, uniform::SampleUniform},
 };
 
-use base4::{Base4, Base4Int};
+use base4::{Base4, Base4Error, Base4Int, Base4PushError};
 
 fn random_ints<T>(len: usize) -> Vec<T>
 where
@@ -154,7 +154,7 @@ fn base4_int_empty() {
 
     (0..70).for_each(|_| ints.push(rng.random_range(0..4_u64)));
 
-    base4_integer.pop();
+    base4_integer.pop_expect();
     base4_integer.push_all(ints.as_slice());
 
     base4_integer.peek_at::<u8>(70);
@@ -183,6 +183,7 @@ fn base4_int_unbounded() {
 }
 
 #[test]
+#[allow(clippy::identity_op)]
 fn bit_shift_multiplication() {
     let a = 4 * 1;
     let b = 1 << 2;
@@ -239,3 +240,2177 @@ fn base4_decode(n: u128, size: usize) -> Vec<u64> {
     }
     ints
 }
+
+fn base4_value(int: &Base4Int) -> u128 {
+    base4_encode(&int.peek_all::<usize>())
+}
+
+fn base4_int(digits: &[u8]) -> Base4Int {
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(digits);
+    base4_integer
+}
+
+#[test]
+fn crc32_of_equal_ints() {
+    let ints = random_ints::<u8>(150);
+
+    let mut a = Base4Int::new();
+    a.push_all(&ints);
+
+    let mut b = Base4Int::new();
+    b.push_all(&ints[..70]);
+    b.push(3_u8);
+    b.pop();
+    ints[70..].iter().for_each(|int| b.push(*int));
+
+    assert!(a.crc32() == b.crc32());
+
+    let flipped = (ints[100] + 1) % 4;
+    let mut c = Base4Int::new();
+    c.push_all(&ints[..100]);
+    c.push(flipped);
+    c.push_all(&ints[101..]);
+
+    assert!(a.crc32() != c.crc32());
+}
+
+#[test]
+fn crc32_check_value() {
+    // Digits whose packed bytes spell out the standard "123456789"
+    // check input.
+    let mut base4_integer = Base4Int::new();
+    for byte in b"123456789" {
+        (0..4).for_each(|i| base4_integer.push((byte >> (6 - 2 * i)) & 0b11));
+    }
+
+    assert!(base4_integer.crc32() == 0xCBF4_3926);
+}
+
+#[test]
+fn fill_from_packed_slice() {
+    let ints = random_ints::<u8>(130);
+    let mut packed = vec![0_u8; 130_usize.div_ceil(4)];
+    for (index, int) in ints.iter().enumerate() {
+        packed[index / 4] |= int << (6 - 2 * (index % 4));
+    }
+
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&[1_u8, 2, 3]);
+
+    assert!(base4_integer.fill_from_slice_packed(&packed, 130).is_ok());
+    assert!(base4_integer.total_len() == 130);
+    assert!(base4_integer.total_blocks() == 3);
+    assert!(base4_integer.peek_all::<u8>() == ints);
+
+    assert!(
+        base4_integer.fill_from_slice_packed(&packed, 140)
+            == Err(Base4Error::BufferTooShort {
+                required: 35,
+                actual: 33
+            })
+    );
+    assert!(base4_integer.peek_all::<u8>() == ints);
+}
+
+#[test]
+fn reverse_complement_inplace() {
+    let ints = random_ints::<u8>(300);
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints);
+
+    base4_integer.rc_inplace();
+
+    let expected: Vec<u8> = ints.iter().rev().map(|int| 3 - int).collect();
+
+    assert!(base4_integer.peek_all::<u8>() == expected);
+    assert!(base4_integer.total_blocks() == 5);
+    (0..300).for_each(|i| assert!(expected[i] == base4_integer.peek_at(i)));
+
+    base4_integer.rc_inplace();
+    assert!(base4_integer.peek_all::<u8>() == ints);
+}
+
+#[test]
+fn nonzero_count() {
+    let mut base4_integer = Base4Int::new();
+    for index in 0..100_u8 {
+        base4_integer.push(if index % 10 < 3 { 0 } else { index % 3 + 1 });
+    }
+
+    assert!(base4_integer.total_len() == 100);
+    assert!(base4_integer.nonzero_count() == 70);
+    assert!(base4_integer.popcount_digit(0_u8) == 30);
+    assert!(Base4Int::new().nonzero_count() == 0);
+}
+
+#[test]
+fn first_and_last() {
+    let ints = random_ints::<u8>(100);
+    let mut base4_integer = Base4Int::new();
+
+    assert!(base4_integer.first().is_none());
+    assert!(base4_integer.last().is_none());
+
+    base4_integer.push_all(&ints);
+
+    assert!(base4_integer.first() == Some(base4_integer.peek_at(0)));
+    assert!(base4_integer.last() == Some(base4_integer.peek_at(99)));
+    assert!(base4_integer.first() == Some(ints[0]));
+    assert!(base4_integer.last() == Some(ints[99]));
+}
+
+#[test]
+fn try_fold_blocks_early_exit() {
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&[1_u8; 130]);
+    base4_integer.push_all(&[3_u8; 70]);
+
+    let mut visited = 0;
+    let found = base4_integer.try_fold_blocks(0, |index, block| {
+        visited += 1;
+        if block.peek_all::<u8>().contains(&3) {
+            Err(index)
+        } else {
+            Ok(index + 1)
+        }
+    });
+
+    assert!(found == Err(2));
+    assert!(visited == 3);
+
+    let none = base4_integer.try_fold_blocks(0, |index, _| Ok::<_, ()>(index + 1));
+    assert!(none == Ok(base4_integer.total_blocks()));
+}
+
+#[test]
+fn build_from_chunks() {
+    let ints = random_ints::<u8>(104);
+    let (first, rest) = ints.split_at(10);
+    let (second, third) = rest.split_at(64);
+
+    let built = Base4Int::build_from_chunks(&[first, second, third]);
+
+    let mut expected = Base4Int::new();
+    expected.push_all(&ints);
+
+    assert!(built.total_len() == 104);
+    assert!(built.total_blocks() == expected.total_blocks());
+    assert!(built.peek_all::<u8>() == expected.peek_all::<u8>());
+    (0..104).for_each(|i| assert!(ints[i] == built.peek_at(i)));
+
+    assert!(Base4Int::build_from_chunks(&[]).total_blocks() == 0);
+}
+
+#[test]
+fn into_dna_iter() {
+    let ints = random_ints::<u8>(200);
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints);
+
+    let expected: String = ints
+        .iter()
+        .map(|int| ['A', 'C', 'G', 'T'][*int as usize])
+        .collect();
+
+    assert!(base4_integer.into_dna_iter().collect::<String>() == expected);
+}
+
+#[test]
+fn base4_shift_digits() {
+    let ints = random_ints::<u8>(10);
+
+    let mut codec = Base4::new();
+    codec.push_all(&ints);
+    codec.shift_left(3);
+
+    assert!(codec.peek_all::<u8>() == ints[3..]);
+    assert!(codec.pop_all::<u8>().len() == 7);
+
+    codec.push_all(&ints);
+    codec.shift_right(3);
+
+    assert!(codec.peek_all::<u8>() == ints[..7]);
+    assert!(codec.pop_all::<u8>().len() == 7);
+
+    let full = random_ints::<u8>(64);
+    codec.push_all(&full);
+    codec.shift_right(64);
+
+    assert!(codec.pop().is_none());
+}
+
+#[test]
+#[should_panic = "shift_left: shift 11 out of bounds (size=10)"]
+fn base4_shift_oob() {
+    let mut codec = Base4::new();
+    codec.push_all(&random_ints::<u8>(10));
+    codec.shift_left(11);
+}
+
+#[test]
+fn same_run_structure() {
+    let mut a = Base4Int::new();
+    let mut b = Base4Int::new();
+    let mut c = Base4Int::new();
+
+    a.push_all(&[0_u8, 0, 1]);
+    b.push_all(&[2_u8, 2, 3]);
+    c.push_all(&[0_u8, 1, 1]);
+
+    assert!(a.same_run_structure(&b));
+    assert!(!a.same_run_structure(&c));
+    assert!(Base4Int::new().same_run_structure(&Base4Int::new()));
+}
+
+#[test]
+fn compress_round_trip() {
+    let pattern = [0_u8, 1, 2, 3, 3, 2, 1, 0, 2, 2];
+    let ints: Vec<u8> = pattern.iter().cycle().take(1000).copied().collect();
+
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints);
+
+    let compressed = base4_integer.compress();
+    let restored = Base4Int::decompress(&compressed).unwrap();
+
+    assert!(compressed.len() < 250 / 4);
+    assert!(restored.total_blocks() == base4_integer.total_blocks());
+    assert!(restored.peek_all::<u8>() == ints);
+
+    let ints = random_ints::<u8>(777);
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints);
+
+    let restored = Base4Int::decompress(&base4_integer.compress()).unwrap();
+    assert!(restored.peek_all::<u8>() == ints);
+
+    let empty = Base4Int::new().compress();
+    assert!(Base4Int::decompress(&empty).unwrap().total_len() == 0);
+}
+
+#[test]
+fn decompress_malformed() {
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&[3_u8; 200]);
+    let compressed = base4_integer.compress();
+
+    assert!(
+        Base4Int::decompress(&compressed[..4]).err() == Some(Base4Error::Malformed { offset: 0 })
+    );
+    assert!(Base4Int::decompress(&compressed[..compressed.len() - 1]).is_err());
+
+    let mut trailing = compressed.clone();
+    trailing.push(0);
+    assert!(Base4Int::decompress(&trailing).is_err());
+
+    // A back-reference before any digit was produced.
+    let mut bad = 4_u64.to_le_bytes().to_vec();
+    bad.extend_from_slice(&[0x80, 1, 0]);
+    assert!(Base4Int::decompress(&bad).err() == Some(Base4Error::Malformed { offset: 8 }));
+}
+
+#[test]
+fn iter_mut_digits() {
+    let ints = random_ints::<u8>(150);
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints);
+
+    for mut digit in base4_integer.iter_mut() {
+        let next = (*digit + 1) % 4;
+        digit.set(next);
+    }
+
+    let expected: Vec<u8> = ints.iter().map(|int| (int + 1) % 4).collect();
+    assert!(base4_integer.peek_all::<u8>() == expected);
+
+    let handles: Vec<_> = base4_integer.iter_mut().enumerate().collect();
+    for (index, mut digit) in handles {
+        digit.set((index % 4) as u8);
+    }
+
+    (0..150).for_each(|i| assert!(base4_integer.peek_at::<usize>(i) == i % 4));
+}
+
+#[test]
+#[should_panic = "DigitMut only accepts value bounded within 0..=3"]
+fn iter_mut_unbounded() {
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&[1_u8, 2]);
+
+    if let Some(mut digit) = base4_integer.iter_mut().next() {
+        digit.set(4);
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn count_kmers() {
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&[0_u8; 20]);
+    for _ in 0..5 {
+        base4_integer.push_all(&[3_u8, 1, 2, 1]);
+        base4_integer.push(0_u8);
+    }
+
+    let counts = base4_integer.count_kmers(4);
+
+    assert!(counts[&0b11_01_10_01] == 5);
+    assert!(counts[&0] == 17);
+    assert!(counts.values().sum::<usize>() == base4_integer.total_len() - 4 + 1);
+
+    let ints = random_ints::<u8>(200);
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints);
+
+    let counts = base4_integer.count_kmers(64);
+    assert!(counts.values().sum::<usize>() == 200 - 64 + 1);
+
+    let mut short = Base4Int::new();
+    short.push_all(&[1_u8, 2, 3]);
+    assert!(short.count_kmers(4).is_empty());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn most_common_kmer() {
+    let mut base4_integer = Base4Int::new();
+    for i in 0..40_u8 {
+        base4_integer.push_all(&[2_u8, 3, 1]);
+        base4_integer.push(i % 4);
+    }
+
+    assert!(base4_integer.most_common_kmer(3) == Some((0b10_11_01, 40)));
+
+    let mut tied = Base4Int::new();
+    tied.push_all(&[3_u8, 3, 0, 1, 1]);
+    assert!(tied.most_common_kmer(1) == Some((1, 2)));
+    assert!(tied.most_common_kmer(6).is_none());
+    assert!(tied.most_common_kmer(100).is_none());
+}
+
+#[test]
+fn pad_to_power_of_two() {
+    let ints = random_ints::<u8>(70);
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints);
+    base4_integer.pad_to_power_of_two(2);
+
+    assert!(base4_integer.total_len() == 128);
+    assert!(base4_integer.total_blocks() == 2);
+    assert!(base4_integer.peek_all::<u8>()[..70] == ints);
+    (70..128).for_each(|i| assert!(base4_integer.peek_at::<u8>(i) == 2));
+
+    let ints = random_ints::<u8>(64);
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints);
+    base4_integer.pad_to_power_of_two(2);
+
+    assert!(base4_integer.peek_all::<u8>() == ints);
+}
+
+#[test]
+fn segment_histogram() {
+    let ints = random_ints::<u8>(70);
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints);
+
+    let histograms = base4_integer.segment_histogram(32);
+    let totals: Vec<usize> = histograms.iter().map(|h| h.iter().sum()).collect();
+
+    assert!(totals == vec![32, 32, 6]);
+
+    for (segment, histogram) in ints.chunks(32).zip(histograms) {
+        (0..4_u8).for_each(|digit| {
+            let expected = segment.iter().filter(|int| **int == digit).count();
+            assert!(histogram[digit as usize] == expected);
+        });
+    }
+
+    assert!(Base4Int::new().segment_histogram(8).is_empty());
+}
+
+#[test]
+fn diff_equal_length() {
+    let ints = random_ints::<u8>(100);
+    let mut variant = ints.clone();
+    for index in [3, 64, 99] {
+        variant[index] = (variant[index] + 2) % 4;
+    }
+
+    let mut a = Base4Int::new();
+    let mut b = Base4Int::new();
+    a.push_all(&ints);
+    b.push_all(&variant);
+
+    let expected: Vec<(usize, u8, u8)> = [3, 64, 99]
+        .iter()
+        .map(|index| (*index, ints[*index], variant[*index]))
+        .collect();
+
+    assert!(a.diff(&b) == expected);
+    assert!(a.diff(&a).is_empty());
+}
+
+#[test]
+#[should_panic = "diff: length mismatch (3 != 2)"]
+fn diff_length_mismatch() {
+    let mut a = Base4Int::new();
+    let mut b = Base4Int::new();
+    a.push_all(&[1_u8, 2, 3]);
+    b.push_all(&[1_u8, 2]);
+    a.diff(&b);
+}
+
+#[test]
+fn apply_diff_rebuilds_variant() {
+    let reference = random_ints::<u8>(150);
+    let mut variant = reference.clone();
+    for index in [0, 63, 64, 149] {
+        variant[index] = (variant[index] + 1) % 4;
+    }
+
+    let mut a = Base4Int::new();
+    let mut b = Base4Int::new();
+    a.push_all(&reference);
+    b.push_all(&variant);
+
+    let patch: Vec<(usize, u8)> = a
+        .diff(&b)
+        .into_iter()
+        .map(|(index, _, digit)| (index, digit))
+        .collect();
+
+    a.apply_diff(&patch);
+
+    assert!(a.peek_all::<u8>() == variant);
+    assert!(a.diff(&b).is_empty());
+}
+
+#[test]
+#[should_panic = "apply_diff: index 10 out of bounds (size=10)"]
+fn apply_diff_oob() {
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&random_ints::<u8>(10));
+    base4_integer.apply_diff(&[(0, 1), (10, 1)]);
+}
+
+#[test]
+fn rank_and_select() {
+    let ints = random_ints::<u8>(200);
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints);
+
+    for digit in 0..4_u8 {
+        for index in 0..=200 {
+            let expected = ints[..index].iter().filter(|int| **int == digit).count();
+            assert!(base4_integer.rank(digit, index) == expected);
+        }
+
+        let positions: Vec<usize> = (0..200).filter(|i| ints[*i] == digit).collect();
+        for (n, position) in positions.iter().enumerate() {
+            assert!(base4_integer.select(digit, n) == Some(*position));
+            assert!(base4_integer.rank(digit, *position) == n);
+        }
+
+        assert!(base4_integer.select(digit, positions.len()).is_none());
+        assert!(base4_integer.rank(digit, 1000) == positions.len());
+    }
+
+    assert!(Base4Int::new().rank(2, 10) == 0);
+    assert!(Base4Int::new().select(2, 0).is_none());
+}
+
+#[test]
+fn wavelet_index_matches_naive() {
+    let ints = random_ints::<u8>(10_000);
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints);
+
+    let index = base4_integer.build_index();
+    let mut rng = rand::rng();
+
+    for _ in 0..500 {
+        let digit = rng.random_range(0..4_u8);
+        let position = rng.random_range(0..=10_050);
+        let n = rng.random_range(0..2_600);
+
+        assert!(index.rank(digit, position) == base4_integer.rank(digit, position));
+        assert!(index.select(digit, n) == base4_integer.select(digit, n));
+    }
+
+    let empty = Base4Int::new();
+    let index = empty.build_index();
+    assert!(index.rank(1, 5) == 0);
+    assert!(index.select(1, 0).is_none());
+}
+
+#[test]
+fn zip_map_digit_addition() {
+    let lhs = random_ints::<u8>(130);
+    let rhs = random_ints::<u8>(130);
+
+    let mut a = Base4Int::new();
+    let mut b = Base4Int::new();
+    a.push_all(&lhs);
+    b.push_all(&rhs);
+
+    let sum = a.zip_map(&b, |x, y| (x + y) % 4);
+    let expected: Vec<u8> = lhs.iter().zip(&rhs).map(|(x, y)| (x + y) % 4).collect();
+
+    assert!(sum.total_blocks() == 3);
+    assert!(sum.peek_all::<u8>() == expected);
+}
+
+#[test]
+#[should_panic = "Base4Int only accepts value bounded within 0..=3"]
+fn zip_map_unbounded() {
+    let mut a = Base4Int::new();
+    a.push_all(&[3_u8, 3]);
+    a.zip_map(&a, |x, y| x + y);
+}
+
+#[test]
+fn gray_code_round_trip() {
+    let ints = random_ints::<u8>(200);
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints);
+
+    assert!(base4_integer.to_gray().from_gray().peek_all::<u8>() == ints);
+
+    let digits = |value: u32| {
+        let mut int = Base4Int::new();
+        (0..4)
+            .rev()
+            .for_each(|i| int.push(((value >> (2 * i)) & 0b11) as u8));
+        int
+    };
+
+    for value in 0..255 {
+        let current = digits(value).to_gray();
+        let next = digits(value + 1).to_gray();
+
+        assert!(current.diff(&next).len() == 1);
+        assert!(current.from_gray().peek_all::<u8>() == digits(value).peek_all::<u8>());
+    }
+}
+
+#[test]
+fn checked_mul_small() {
+    let ints = random_ints::<u8>(40);
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints);
+
+    for factor in [0_u8, 1, 3, 7, 255] {
+        let product = base4_integer.checked_mul_small(factor, 64).unwrap();
+        assert!(base4_value(&product) == base4_value(&base4_integer) * factor as u128);
+    }
+
+    let mut full = Base4Int::new();
+    full.push_all(&[3_u8; 10]);
+
+    assert!(full.checked_mul_small(2, 10).is_none());
+    assert!(full.checked_mul_small(2, 11).unwrap().total_len() == 11);
+    assert!(full.checked_mul_small(0, 1).unwrap().peek_all::<u8>() == vec![0]);
+}
+
+#[test]
+fn to_bitpacked_into_reuses_buffer() {
+    let first = random_ints::<u8>(200);
+    let second = random_ints::<u8>(77);
+
+    let mut a = Base4Int::new();
+    let mut b = Base4Int::new();
+    a.push_all(&first);
+    b.push_all(&second);
+
+    let mut out = Vec::with_capacity(64);
+    let buffer = out.as_ptr();
+    let mut decoded = Base4Int::new();
+
+    a.to_bitpacked_into(&mut out);
+    assert!(out.len() == 50);
+    decoded.fill_from_slice_packed(&out, 200).unwrap();
+    assert!(decoded.peek_all::<u8>() == first);
+
+    b.to_bitpacked_into(&mut out);
+    assert!(out.len() == 20);
+    decoded.fill_from_slice_packed(&out, 77).unwrap();
+    assert!(decoded.peek_all::<u8>() == second);
+
+    assert!(out.as_ptr() == buffer);
+    assert!(out.capacity() == 64);
+}
+
+#[test]
+fn differing_positions() {
+    let ints = random_ints::<u8>(105);
+    let mut variant = ints[..100].to_vec();
+    for index in [5, 50, 70] {
+        variant[index] = (variant[index] + 3) % 4;
+    }
+
+    let mut a = Base4Int::new();
+    let mut b = Base4Int::new();
+    a.push_all(&variant);
+    b.push_all(&ints);
+
+    let expected: Vec<usize> = [5, 50, 70].into_iter().chain(100..105).collect();
+
+    assert!(a.differing_positions(&b) == expected);
+    assert!(b.differing_positions(&a) == expected);
+    assert!(a.differing_positions(&a).is_empty());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn longest_repeat() {
+    let repeat = random_ints::<u8>(20);
+
+    let mut ints = random_ints::<u8>(60);
+    ints.push(0);
+    ints.extend(&repeat);
+    ints.push(2);
+    ints.extend(random_ints::<u8>(60));
+    ints.push(1);
+    ints.extend(&repeat);
+    ints.push(3);
+    ints.extend(random_ints::<u8>(60));
+
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints);
+
+    let (first, second, len) = base4_integer.longest_repeat();
+
+    assert!(len >= 20);
+    assert!((first, second, len) == (61, 143, 20));
+    assert!(ints[first..first + len] == ints[second..second + len]);
+
+    let mut unique = Base4Int::new();
+    unique.push_all(&[0_u8, 1, 2, 3]);
+    assert!(unique.longest_repeat() == (0, 0, 0));
+    assert!(Base4Int::new().longest_repeat() == (0, 0, 0));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn serialize_stream_round_trip() {
+    let lengths: Vec<usize> = (0..1000).map(|i| i % 150).collect();
+    let inputs: Vec<Vec<u8>> = lengths.iter().map(|len| random_ints(*len)).collect();
+
+    let ints = inputs.iter().map(|digits| {
+        let mut base4_integer = Base4Int::new();
+        base4_integer.push_all(digits);
+        base4_integer
+    });
+
+    let mut out = Vec::new();
+    Base4Int::serialize_stream(ints, &mut out).unwrap();
+
+    let mut reader = out.as_slice();
+    let mut records = Base4Int::deserialize_stream(&mut reader);
+
+    for digits in inputs.iter() {
+        let base4_integer = records.next().unwrap().unwrap();
+        assert!(base4_integer.peek_all::<u8>() == *digits);
+    }
+    assert!(records.next().is_none());
+
+    let mut truncated = &out[..out.len() - 1];
+    let records: Vec<_> = Base4Int::deserialize_stream(&mut truncated).collect();
+
+    assert!(records.len() == 1000);
+    assert!(records[..999].iter().all(|record| record.is_ok()));
+    assert!(records[999].is_err());
+}
+
+#[test]
+fn from_counts() {
+    let base4_integer = Base4Int::from_counts([10, 20, 5, 0]);
+
+    assert!(base4_integer.total_len() == 35);
+    assert!(base4_integer.total_blocks() == 1);
+    assert!(base4_integer.segment_histogram(35) == vec![[10, 20, 5, 0]]);
+
+    let mut expected = vec![0_u8; 10];
+    expected.extend([1; 20]);
+    expected.extend([2; 5]);
+    assert!(base4_integer.peek_all::<u8>() == expected);
+
+    let counts = [70, 1, 130, 64];
+    let base4_integer = Base4Int::from_counts(counts);
+
+    assert!(base4_integer.total_len() == 265);
+    assert!(base4_integer.total_blocks() == 5);
+    assert!(base4_integer.segment_histogram(265) == vec![counts]);
+    (0..4).for_each(|digit| assert!(base4_integer.rank(digit, 265) == counts[digit as usize]));
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn weighted_random_skew() {
+    let mut rng = rand::rng();
+    let base4_integer = Base4Int::weighted_random([1.0, 7.0, 1.0, 1.0], 20_000, &mut rng);
+
+    let histogram = base4_integer.segment_histogram(20_000)[0];
+
+    assert!(base4_integer.total_len() == 20_000);
+    assert!((13_000..15_000).contains(&histogram[1]));
+    [0, 2, 3]
+        .iter()
+        .for_each(|digit| assert!((1_500..2_500).contains(&histogram[*digit])));
+}
+
+#[test]
+fn positions_where() {
+    let ints = random_ints::<u8>(150);
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints);
+
+    let positions: Vec<usize> = base4_integer.positions_where(|digit| digit >= 2).collect();
+    let expected: Vec<usize> = base4_integer
+        .peek_all::<u8>()
+        .iter()
+        .enumerate()
+        .filter(|(_, digit)| **digit >= 2)
+        .map(|(index, _)| index)
+        .collect();
+
+    assert!(positions == expected);
+    assert!(base4_integer.positions_where(|_| false).next().is_none());
+}
+
+#[test]
+fn collapse_to_block() {
+    let ints = random_ints::<u8>(50);
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints);
+
+    let block = base4_integer.collapse_to_block().unwrap();
+    assert!(block.peek_all::<u8>() == ints);
+
+    let restored = Base4Int::from(block);
+    assert!(restored.total_blocks() == 1);
+    assert!(restored.peek_all::<u8>() == ints);
+
+    base4_integer.push_all(&random_ints::<u8>(50));
+    assert!(base4_integer.collapse_to_block().is_none());
+
+    let empty = Base4Int::new().collapse_to_block().unwrap();
+    assert!(Base4Int::from(empty).total_blocks() == 0);
+}
+
+#[test]
+fn gc_skew() {
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&[1_u8; 40]);
+    base4_integer.push_all(&[0_u8, 3, 0, 3]);
+    base4_integer.push_all(&[2_u8; 60]);
+
+    let cumulative = base4_integer.gc_skew_cumulative();
+    let (origin, minimum) = cumulative
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, skew)| **skew)
+        .unwrap();
+
+    assert!(base4_integer.gc_skew() == 20);
+    assert!(cumulative.len() == 105);
+    assert!(cumulative[104] == 20);
+    assert!((origin, *minimum) == (40, -40));
+
+    let ints = random_ints::<u8>(300);
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints);
+
+    assert!(base4_integer.gc_skew() == *base4_integer.gc_skew_cumulative().last().unwrap());
+}
+
+#[test]
+fn canonical_rotation() {
+    let mut ints = random_ints::<u8>(90);
+    ints.extend_from_slice(&[0, 0, 1, 0, 0, 1]);
+
+    let minimal = (0..ints.len())
+        .map(|start| [&ints[start..], &ints[..start]].concat())
+        .min()
+        .unwrap();
+
+    for start in 0..ints.len() {
+        let mut rotated = Base4Int::new();
+        rotated.push_all(&ints[start..]);
+        rotated.push_all(&ints[..start]);
+
+        let canonical = rotated.canonical_rotation();
+        assert!(canonical.peek_all::<u8>() == minimal);
+        assert!(canonical.total_blocks() == 2);
+    }
+
+    assert!(Base4Int::new().canonical_rotation().total_len() == 0);
+}
+
+#[test]
+fn is_rotation_of() {
+    let base = base4_int(&[0, 1, 2, 3]);
+
+    assert!(base4_int(&[1, 2, 3, 0]).is_rotation_of(&base));
+    assert!(!base4_int(&[1, 3, 2, 0]).is_rotation_of(&base));
+    assert!(!base4_int(&[1, 2, 3]).is_rotation_of(&base));
+    assert!(base.is_rotation_of(&base));
+
+    let ints = random_ints::<u8>(150);
+    let rotated = [&ints[37..], &ints[..37]].concat();
+
+    assert!(base4_int(&rotated).is_rotation_of(&base4_int(&ints)));
+    assert!(Base4Int::new().is_rotation_of(&Base4Int::new()));
+}
+
+#[test]
+fn batch_peek() {
+    let ints = random_ints::<u8>(128);
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints);
+
+    let indices = [100, 0, 63, 64];
+    let expected: Vec<u8> = indices.iter().map(|i| base4_integer.peek_at(*i)).collect();
+
+    assert!(base4_integer.batch_peek(&indices) == expected);
+    assert!(base4_integer.batch_peek(&[]).is_empty());
+}
+
+#[test]
+#[should_panic = "peek_at: index 128 out of bounds (size=128)"]
+fn batch_peek_oob() {
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&random_ints::<u8>(128));
+    base4_integer.batch_peek(&[0, 128]);
+}
+
+#[test]
+fn reverse_range() {
+    let ints = random_ints::<u8>(100);
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints);
+
+    base4_integer.reverse_range(10..20);
+
+    let mut expected = ints.clone();
+    expected[10..20].reverse();
+    assert!(base4_integer.peek_all::<u8>() == expected);
+
+    base4_integer.reverse_range(50..80);
+    expected[50..80].reverse();
+    assert!(base4_integer.peek_all::<u8>() == expected);
+
+    base4_integer.reverse_range(30..30);
+    assert!(base4_integer.peek_all::<u8>() == expected);
+}
+
+#[test]
+#[should_panic = "reverse_range: range 90..101 out of bounds (size=100)"]
+fn reverse_range_oob() {
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&random_ints::<u8>(100));
+    base4_integer.reverse_range(90..101);
+}
+
+#[test]
+fn pack_efficiency() {
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&random_ints::<u8>(128));
+
+    assert!(base4_integer.pack_efficiency() == 1.0);
+
+    base4_integer.push(1_u8);
+    assert!(base4_integer.pack_efficiency() < 1.0);
+    assert!(base4_integer.pack_efficiency() == 129.0 / 192.0);
+
+    assert!(Base4Int::new().pack_efficiency() == 1.0);
+}
+
+#[test]
+fn try_into_array() {
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&[0_u64, 1, 2, 3, 2, 1, 0]);
+
+    assert!(base4_integer.try_into_array::<7>() == Some([0, 1, 2, 3, 2, 1, 0]));
+    assert!(base4_integer.try_into_array::<6>().is_none());
+    assert!(base4_integer.try_into_array::<8>().is_none());
+
+    let ints = random_ints::<u8>(100);
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints);
+
+    assert!(base4_integer.try_into_array::<100>().unwrap() == ints[..]);
+}
+
+#[test]
+fn concat_inplace_many() {
+    let fragments: Vec<Vec<u8>> = (0..50).map(|i| random_ints(i * 3 % 70)).collect();
+
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&[1_u8, 2, 3]);
+
+    let others = fragments
+        .iter()
+        .map(|digits| {
+            let mut other = Base4Int::new();
+            other.push_all(digits);
+            other
+        })
+        .collect();
+
+    base4_integer.concat_inplace_many(others);
+
+    let mut expected = Base4Int::new();
+    expected.push_all(&[1_u8, 2, 3]);
+    fragments
+        .iter()
+        .for_each(|digits| expected.push_all(digits));
+
+    assert!(base4_integer.total_len() == expected.total_len());
+    assert!(base4_integer.total_blocks() == expected.total_blocks());
+    assert!(base4_integer.peek_all::<u8>() == expected.peek_all::<u8>());
+}
+
+#[test]
+fn ascii_digits_round_trip() {
+    let ints = random_ints::<u8>(150);
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints);
+
+    let ascii = base4_integer.as_ascii_digits();
+    assert!(ascii.iter().all(|byte| (b'0'..=b'3').contains(byte)));
+
+    let parsed = Base4Int::from_ascii_digits(&ascii).unwrap();
+    assert!(parsed.peek_all::<u8>() == ints);
+
+    assert!(
+        Base4Int::from_ascii_digits(b"0124").err()
+            == Some(Base4Error::InvalidDigit {
+                ch: '4',
+                position: 3
+            })
+    );
+}
+
+#[test]
+fn split_on_delimiter() {
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&[0_u8, 1, 3, 2, 3, 0]);
+
+    let parts = base4_integer.split_on(3);
+
+    assert!(parts.len() == 3);
+    assert!(parts.iter().map(Base4Int::total_len).collect::<Vec<_>>() == vec![2, 1, 1]);
+    assert!(parts[0].peek_all::<u8>() == vec![0, 1]);
+    assert!(parts[1].peek_all::<u8>() == vec![2]);
+    assert!(parts[2].peek_all::<u8>() == vec![0]);
+
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&[3_u8, 3]);
+    base4_integer.push_all(&[1_u8; 100]);
+    base4_integer.push(3_u8);
+
+    let parts = base4_integer.split_on(3);
+    assert!(parts.iter().map(Base4Int::total_len).collect::<Vec<_>>() == vec![0, 0, 100, 0]);
+    assert!(parts[2].total_blocks() == 2);
+}
+
+#[test]
+fn replace_subsequence() {
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&[1_u8, 3, 3, 2, 3, 3, 3, 0]);
+    base4_integer.push_all(&[2_u8; 70]);
+    base4_integer.push_all(&[3_u8, 3]);
+
+    let count = base4_integer.replace_subsequence(&[3, 3], &[0]);
+
+    let mut expected = vec![1_u8, 0, 2, 0, 3, 0];
+    expected.extend([2; 70]);
+    expected.push(0);
+
+    assert!(count == 3);
+    assert!(base4_integer.peek_all::<u8>() == expected);
+    assert!(base4_integer.total_blocks() == 2);
+
+    assert!(base4_integer.replace_subsequence(&[0, 2, 0, 3], &[1, 1, 1, 1, 1]) == 1);
+    assert!(base4_integer.total_len() == 78);
+    assert!(base4_integer.replace_subsequence(&[3, 3], &[]) == 0);
+}
+
+#[test]
+fn pop_until() {
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&[0_u8, 1, 2, 3, 0]);
+
+    assert!(base4_integer.pop_until(|digit| digit == 3) == vec![0]);
+    assert!(base4_integer.last() == Some(3));
+    assert!(base4_integer.total_len() == 4);
+
+    assert!(base4_integer.pop_until(|digit| digit == 3).is_empty());
+
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&[1_u8; 70]);
+
+    assert!(base4_integer.pop_until(|digit| digit == 0) == vec![1; 70]);
+    assert!(base4_integer.total_blocks() == 0);
+}
+
+#[test]
+fn checked_sub() {
+    let a = base4_int(&[1, 0, 0]);
+    let b = base4_int(&[0, 0, 3]);
+    let difference = a.checked_sub(&b).unwrap();
+
+    assert!(base4_value(&difference) == 16 - 3);
+    assert!(difference.peek_all::<u8>() == vec![3, 1]);
+    assert!(b.checked_sub(&a).is_none());
+    assert!(a.checked_sub(&a).unwrap().peek_all::<u8>() == vec![0]);
+
+    let lhs = base4_int(&random_ints::<u8>(60));
+    let rhs = base4_int(&random_ints::<u8>(45));
+    let difference = lhs.checked_sub(&rhs);
+
+    if base4_value(&lhs) >= base4_value(&rhs) {
+        assert!(base4_value(&difference.unwrap()) == base4_value(&lhs) - base4_value(&rhs));
+    } else {
+        assert!(difference.is_none());
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn two_bit_round_trip() {
+    let ints = random_ints::<u8>(500);
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints);
+
+    let mut out = Vec::new();
+    base4_integer.write_2bit("chrM", &mut out).unwrap();
+
+    assert!(out.len() == 4 + 4 + 8 + 125);
+
+    let (name, read) = Base4Int::read_2bit(&mut out.as_slice()).unwrap();
+
+    assert!(name == "chrM");
+    assert!(read.peek_all::<u8>() == ints);
+
+    let truncated = Base4Int::read_2bit(&mut &out[..out.len() - 1]);
+    assert!(truncated.unwrap_err().kind() == std::io::ErrorKind::UnexpectedEof);
+
+    let mut invalid = out.clone();
+    invalid[4] = 0xFF;
+    let invalid = Base4Int::read_2bit(&mut invalid.as_slice());
+    assert!(invalid.unwrap_err().kind() == std::io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn iter_blocks_with_offset() {
+    let ints = random_ints::<u8>(300);
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints);
+
+    let blocks: Vec<_> = base4_integer.iter_blocks_with_offset().collect();
+    let offsets: Vec<usize> = blocks.iter().map(|(offset, _)| *offset).collect();
+
+    assert!(offsets == vec![0, 64, 128, 192, 256]);
+    assert!(blocks[4].1.peek_all::<u8>().len() == 300 % 64);
+
+    for (offset, block) in blocks {
+        let digits = block.peek_all::<u8>();
+        assert!(digits == ints[offset..offset + digits.len()]);
+    }
+}
+
+#[test]
+fn minimizers_match_brute_force() {
+    let ints = random_ints::<u8>(300);
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints);
+
+    for (k, w) in [(1, 1), (3, 4), (5, 10), (11, 7), (64, 3)] {
+        let kmers: Vec<u128> = (0..=ints.len() - k)
+            .map(|start| {
+                ints[start..start + k]
+                    .iter()
+                    .fold(0_u128, |kmer, digit| (kmer << 2) | *digit as u128)
+            })
+            .collect();
+
+        let mut expected: Vec<(usize, u128)> = Vec::new();
+        for start in 0..=kmers.len() - w {
+            let selected = (start..start + w)
+                .map(|position| (position, kmers[position]))
+                .min_by_key(|(position, kmer)| (*kmer, *position))
+                .unwrap();
+
+            if expected.last() != Some(&selected) {
+                expected.push(selected);
+            }
+        }
+
+        assert!(base4_integer.minimizers(k, w) == expected);
+    }
+
+    let mut short = Base4Int::new();
+    short.push_all(&[0_u8, 1, 2]);
+    assert!(short.minimizers(2, 3).is_empty());
+}
+
+#[test]
+fn try_push_out_of_range() {
+    let mut base4_integer = Base4Int::new();
+
+    assert!(base4_integer.try_push(2_u8).is_ok());
+    assert!(base4_integer.try_push(4_u64) == Err(Base4Error::OutOfRange { value: 4 }));
+    assert!(base4_integer.try_push(u128::MAX) == Err(Base4Error::OutOfRange { value: u128::MAX }));
+    assert!(base4_integer.peek_all::<u8>() == vec![2]);
+}
+
+#[test]
+fn pop_empty_base4_int() {
+    let mut base4_integer = Base4Int::new();
+    assert!(base4_integer.pop().is_none());
+
+    base4_integer.push_all(&[1_u8; 65]);
+
+    let mut popped = 0;
+    while let Some(digit) = base4_integer.pop() {
+        assert!(digit == 1);
+        popped += 1;
+    }
+
+    assert!(popped == 65);
+    assert!(base4_integer.total_blocks() == 0);
+    assert!(base4_integer.pop().is_none());
+}
+
+#[test]
+fn is_empty() {
+    let mut base4_integer = Base4Int::new();
+    assert!(base4_integer.is_empty());
+
+    base4_integer.push_all(&random_ints::<u8>(70));
+    assert!(!base4_integer.is_empty());
+
+    base4_integer.pop_all::<u8>();
+    assert!(base4_integer.is_empty());
+
+    let mut codec = Base4::new();
+    assert!(codec.is_empty());
+
+    codec.push(3_u8);
+    assert!(!codec.is_empty());
+
+    codec.pop();
+    assert!(codec.is_empty());
+}
+
+#[test]
+fn base4_len() {
+    let mut codec = Base4::new();
+    assert!(codec.is_empty());
+
+    codec.push_all(&random_ints::<u8>(40));
+    assert!(codec.len() == 40);
+
+    codec.pop();
+    assert!(codec.len() == 39);
+
+    codec.push_all(&random_ints::<u8>(25));
+    assert!(codec.len() == 64);
+    assert!(!codec.push(1_u8));
+}
+
+#[test]
+fn clear_keeps_capacity() {
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&random_ints::<u8>(1000));
+    base4_integer.clear();
+
+    assert!(base4_integer.is_empty());
+    assert!(base4_integer.total_blocks() == 0);
+
+    let ints = random_ints::<u8>(200);
+    base4_integer.push_all(&ints);
+    assert!(base4_integer.peek_all::<u8>() == ints);
+
+    let mut codec = Base4::new();
+    codec.push_all(&random_ints::<u8>(64));
+    codec.clear();
+
+    assert!(codec.is_empty());
+    assert!(codec.push_all(&[1_u8, 2]));
+    assert!(codec.peek_all::<u8>() == vec![1, 2]);
+}
+
+#[test]
+fn base4_iter() {
+    let ints = random_ints::<u8>(64);
+    let mut codec = Base4::new();
+    codec.push_all(&ints);
+
+    assert!(codec.iter().collect::<Vec<_>>() == ints);
+    assert!(codec.iter().len() == 64);
+
+    let mut reversed = ints.clone();
+    reversed.reverse();
+    assert!(codec.iter().rev().collect::<Vec<_>>() == reversed);
+
+    let mut iter = codec.iter();
+    assert!(iter.next() == Some(ints[0]));
+    assert!(iter.next_back() == Some(ints[63]));
+    assert!(iter.len() == 62);
+    assert!(iter.count() == 62);
+
+    assert!(Base4::new().iter().next().is_none());
+}
+
+#[test]
+fn base4_int_into_iter() {
+    let ints = random_ints::<u8>(200);
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints);
+
+    let mut iter = base4_integer.into_iter();
+    assert!(iter.len() == 200);
+
+    let first = iter.by_ref().take(70).collect::<Vec<_>>();
+    assert!(first == ints[..70]);
+    assert!(iter.len() == 130);
+    assert!(iter.collect::<Vec<_>>() == ints[70..]);
+
+    assert!(Base4Int::new().into_iter().next().is_none());
+}
+
+#[test]
+fn base4_int_from_iter() {
+    let ints = random_ints::<u8>(150);
+    let base4_integer = ints.iter().copied().collect::<Base4Int>();
+
+    assert!(base4_integer.total_len() == 150);
+    assert!(base4_integer.total_blocks() == 3);
+    assert!(base4_integer.peek_all::<u8>() == ints);
+
+    assert!(std::iter::empty::<u8>().collect::<Base4Int>().is_empty());
+}
+
+#[test]
+#[should_panic = "Base4Int only accepts value bounded within 0..=3"]
+fn base4_int_from_iter_out_of_range() {
+    let _ = [0_u8, 1, 4].into_iter().collect::<Base4Int>();
+}
+
+#[test]
+fn base4_int_extend() {
+    let ints = random_ints::<u8>(100);
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints[..30]);
+    base4_integer.extend(ints[30..].iter().copied());
+
+    assert!(base4_integer.total_blocks() == 2);
+    assert!(base4_integer[0].len() == 64);
+    assert!(base4_integer.peek_all::<u8>() == ints);
+
+    let (tx, rx) = std::sync::mpsc::channel();
+    std::thread::spawn(move || (0..10_u8).for_each(|i| tx.send(i % 4).unwrap()));
+    base4_integer.extend(rx);
+    assert!(base4_integer.total_len() == 110);
+    assert!(base4_integer.peek_at::<u8>(109) == 1);
+}
+
+#[test]
+fn display_matches_peek_all() {
+    let ints = random_ints::<u8>(150);
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints);
+
+    let expected = ints.iter().map(|d| (b'0' + d) as char).collect::<String>();
+    assert!(format!("{}", base4_integer) == expected);
+    assert!(format!("{}", base4_integer[2]) == expected[128..]);
+
+    assert!(Base4Int::new().to_string().is_empty());
+    assert!(Base4::new().to_string().is_empty());
+}
+
+#[test]
+fn from_str_round_trip() {
+    let ints = random_ints::<u8>(150);
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints);
+
+    let parsed = base4_integer.to_string().parse::<Base4Int>().unwrap();
+    assert!(parsed.peek_all::<u8>() == ints);
+    assert!(parsed.total_blocks() == 3);
+
+    assert!("".parse::<Base4Int>().unwrap().is_empty());
+    assert!(
+        "0123\n".parse::<Base4Int>().err()
+            == Some(Base4Error::InvalidDigit {
+                ch: '\n',
+                position: 4
+            })
+    );
+    assert!(
+        "é4".parse::<Base4Int>().err()
+            == Some(Base4Error::InvalidDigit {
+                ch: 'é',
+                position: 0
+            })
+    );
+}
+
+#[test]
+fn bytes_round_trip() {
+    for len in [0, 1, 3, 4, 5, 63, 64, 65, 130] {
+        let ints = random_ints::<u8>(len);
+        let mut base4_integer = Base4Int::new();
+        base4_integer.push_all(&ints);
+
+        let bytes = base4_integer.to_bytes();
+        assert!(bytes.len() == 8 + len.div_ceil(4));
+
+        let decoded = Base4Int::from_bytes(&bytes).unwrap();
+        assert!(decoded.total_blocks() == base4_integer.total_blocks());
+        assert!(decoded.peek_all::<u8>() == ints);
+    }
+
+    let mut bytes = vec![3, 0, 0, 0, 0, 0, 0, 0, 0b11_10_01_11];
+    assert!(Base4Int::from_bytes(&bytes).unwrap().peek_all::<u8>() == vec![3, 2, 1]);
+
+    bytes.push(0);
+    assert!(Base4Int::from_bytes(&bytes).err() == Some(Base4Error::Malformed { offset: 9 }));
+    assert!(
+        Base4Int::from_bytes(&bytes[..8]).err()
+            == Some(Base4Error::BufferTooShort {
+                required: 9,
+                actual: 8
+            })
+    );
+    assert!(
+        Base4Int::from_bytes(&bytes[..3]).err()
+            == Some(Base4Error::BufferTooShort {
+                required: 8,
+                actual: 3
+            })
+    );
+}
+
+#[test]
+fn dna_round_trip() {
+    let ints = random_ints::<u8>(150);
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints);
+
+    let dna = base4_integer.to_dna();
+    assert!(dna.len() == 150);
+
+    let decoded = Base4Int::from_dna(&dna.to_lowercase()).unwrap();
+    assert!(decoded.peek_all::<u8>() == ints);
+    assert!(base4::NUCLEOTIDES[ints[0] as usize] == dna.chars().next().unwrap());
+
+    assert!(Base4Int::from_dna("").unwrap().is_empty());
+    assert!(
+        Base4Int::from_dna("acgtN").err()
+            == Some(Base4Error::InvalidDigit {
+                ch: 'N',
+                position: 4
+            })
+    );
+}
+
+#[test]
+fn u128_conversion() {
+    let ints = random_ints::<usize>(64);
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints.iter().map(|i| *i as u64).collect::<Vec<_>>());
+
+    let value = base4_integer.try_to_u128().unwrap();
+    assert!(value == base4_encode(&ints));
+    assert!(base4_integer[0].to_u128() == value);
+
+    let codec = Base4::from_u128(value, 64);
+    assert!(codec.peek_all::<u64>() == base4_integer.peek_all::<u64>());
+
+    assert!(Base4Int::new().try_to_u128() == Some(0));
+    base4_integer.push(1_u8);
+    assert!(base4_integer.try_to_u128().is_none());
+}
+
+#[test]
+#[should_panic = "value 16 does not fit into 2 digits"]
+fn u128_conversion_overflow() {
+    Base4::from_u128(16, 2);
+}
+
+#[test]
+fn logical_equality() {
+    let ints = random_ints::<u8>(150);
+    let mut a = Base4Int::new();
+    a.push_all(&ints);
+
+    let b = Base4Int::build_from_chunks(&[&ints[..10], &ints[10..]]);
+    assert!(a == b);
+
+    let mut c = Base4Int::from(Base4::from_u128(0b01_10, 2));
+    c.push(3_u8);
+    assert!(c == "123".parse().unwrap());
+    assert!(c != "1230".parse().unwrap());
+    assert!(c != "120".parse().unwrap());
+
+    a.push(0_u8);
+    assert!(a != b);
+    assert!(Base4Int::new() == Base4Int::default());
+    assert!(a[0] == b[0]);
+    assert!(a[2] != b[2]);
+}
+
+#[test]
+fn lexicographic_ordering() {
+    let mut rng = rand::rng();
+    let mut digits = (0..50)
+        .map(|_| random_ints::<u8>(rng.random_range(0..150)))
+        .collect::<Vec<_>>();
+    digits.push(digits[0][..digits[0].len() / 2].to_vec());
+
+    let mut ints = digits
+        .iter()
+        .map(|ints| ints.iter().copied().collect::<Base4Int>())
+        .collect::<Vec<_>>();
+
+    digits.sort();
+    ints.sort();
+
+    for (int, expected) in ints.iter().zip(digits.iter()) {
+        assert!(int.peek_all::<u8>() == *expected);
+    }
+
+    let a = "0123".parse::<Base4Int>().unwrap();
+    assert!(a.cmp(&"0123".parse().unwrap()) == std::cmp::Ordering::Equal);
+    assert!(a[0] < "013".parse::<Base4Int>().unwrap()[0]);
+}
+
+#[test]
+fn hash_matches_equality() {
+    use std::collections::HashMap;
+
+    let ints = random_ints::<u8>(150);
+    let mut pushed = Base4Int::new();
+    pushed.push_all(&ints);
+
+    let built = Base4Int::build_from_chunks(&[&ints[..1], &ints[1..100], &ints[100..]]);
+    let mut extended = Base4Int::from(Base4::from_u128(ints[0] as u128, 1));
+    extended.extend(ints[1..].iter().copied());
+
+    let mut map = HashMap::new();
+    map.insert(pushed, 1);
+    map.insert(built, 2);
+    *map.entry(extended).or_insert(0) += 1;
+
+    assert!(map.len() == 1);
+    assert!(map.values().next() == Some(&3));
+
+    map.insert(ints[1..].iter().copied().collect(), 4);
+    assert!(map.len() == 2);
+}
+
+#[test]
+fn clone_is_deep() {
+    let ints = random_ints::<u8>(100);
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints);
+
+    let snapshot = base4_integer.clone();
+    base4_integer.push(3_u8);
+    base4_integer.iter_mut().for_each(|mut digit| digit.set(0));
+
+    assert!(snapshot.peek_all::<u8>() == ints);
+    assert!(snapshot.total_len() == 100);
+
+    let mut codec = snapshot[1].clone();
+    codec.push(1_u8);
+    assert!(snapshot[1].len() == 36);
+    assert!(codec.len() == 37);
+}
+
+#[test]
+fn reverse_round_trip() {
+    for len in [0, 1, 63, 64, 65, 130, 256] {
+        let ints = random_ints::<u8>(len);
+        let mut base4_integer = Base4Int::new();
+        base4_integer.push_all(&ints);
+
+        base4_integer.reverse();
+        let mut reversed = ints.clone();
+        reversed.reverse();
+        assert!(base4_integer.peek_all::<u8>() == reversed);
+        assert!(base4_integer.total_blocks() == len.div_ceil(64));
+
+        base4_integer.reverse();
+        assert!(base4_integer.peek_all::<u8>() == ints);
+    }
+
+    let mut codec = Base4::new();
+    codec.push_all(&random_ints::<u8>(64));
+    let original = codec.clone();
+    codec.reverse();
+    assert!(codec.iter().eq(original.iter().rev()));
+}
+
+#[test]
+fn set_at_across_blocks() {
+    let mut ints = random_ints::<u8>(150);
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints);
+
+    for index in [0, 63, 64, 127, 128, 149] {
+        let value = (ints[index] + 1) % 4;
+        base4_integer.set_at(index, value);
+        ints[index] = value;
+    }
+
+    assert!(base4_integer.peek_all::<u8>() == ints);
+}
+
+#[test]
+#[should_panic = "set_at: index 150 out of bounds (size=150)"]
+fn set_at_out_of_bounds() {
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&random_ints::<u8>(150));
+    base4_integer.set_at(150, 0_u8);
+}
+
+#[test]
+#[should_panic = "Base4Int only accepts value bounded within 0..=3, got 4"]
+fn set_at_out_of_range() {
+    let mut codec = Base4::new();
+    codec.push_all(&[0_u8, 1]);
+    codec.set_at(0, 4_u8);
+}
+
+#[test]
+fn insert_and_remove_across_blocks() {
+    let mut ints = random_ints::<u8>(128);
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints);
+
+    for (index, value) in [(64, 3_u8), (63, 2), (0, 1), (131, 0), (131, 2)] {
+        base4_integer.insert_at(index, value);
+        ints.insert(index, value);
+        assert!(base4_integer.peek_all::<u8>() == ints);
+    }
+    assert!(base4_integer.total_blocks() == 3);
+    assert!(base4_integer[1].len() == 64);
+
+    for index in [64, 63, 0, 129, 0] {
+        assert!(base4_integer.remove_at(index) == ints.remove(index));
+        assert!(base4_integer.peek_all::<u8>() == ints);
+    }
+    assert!(base4_integer.total_blocks() == 2);
+    assert!(base4_integer[0].len() == 64);
+}
+
+#[test]
+#[should_panic = "remove_at: index 0 out of bounds (size=0)"]
+fn remove_at_empty() {
+    Base4Int::new().remove_at(0);
+}
+
+#[test]
+fn front_and_back() {
+    let ints = random_ints::<u8>(130);
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints);
+
+    assert!(base4_integer.front::<u8>() == Some(ints[0]));
+    assert!(base4_integer.back::<u32>() == Some(ints[129] as u32));
+
+    let mut popped = base4_integer.clone();
+    assert!(popped.pop_all::<u8>().first().copied() == base4_integer.front());
+    assert!(popped.front::<u8>().is_none());
+    assert!(popped.back::<u8>().is_none());
+}
+
+#[test]
+fn append_tops_up_seam() {
+    let ints = random_ints::<u8>(100);
+    let mut base4_integer = Base4Int::new();
+    let mut other = Base4Int::new();
+    base4_integer.push_all(&ints[..30]);
+    other.push_all(&ints[30..]);
+
+    base4_integer.append(other);
+
+    assert!(base4_integer.total_blocks() == 2);
+    assert!(base4_integer[0].len() == 64);
+    (0..100).for_each(|i| assert!(ints[i] == base4_integer.peek_at::<u8>(i)));
+
+    base4_integer.append(Base4Int::new());
+    assert!(base4_integer.total_len() == 100);
+}
+
+#[test]
+fn split_off_and_append() {
+    let ints = random_ints::<u8>(200);
+    let mut original = Base4Int::new();
+    original.push_all(&ints);
+
+    for index in [0, 1, 63, 64, 65, 128, 150, 199, 200] {
+        let mut base4_integer = original.clone();
+        let suffix = base4_integer.split_off(index);
+
+        assert!(base4_integer.peek_all::<u8>() == ints[..index]);
+        assert!(suffix.peek_all::<u8>() == ints[index..]);
+        assert!(suffix.total_blocks() == (200 - index).div_ceil(64));
+        (0..suffix.total_len()).for_each(|i| assert!(suffix.peek_at::<u8>(i) == ints[index + i]));
+
+        base4_integer.append(suffix);
+        assert!(base4_integer == original);
+        assert!(base4_integer.total_blocks() == 4);
+    }
+}
+
+#[test]
+fn get_range_across_blocks() {
+    let ints = random_ints::<u8>(200);
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints);
+
+    for range in [
+        0..0,
+        0..200,
+        5..10,
+        60..70,
+        63..129,
+        128..200,
+        200..200,
+        64..64,
+    ] {
+        assert!(base4_integer.get_range::<u8>(range.clone()) == ints[range]);
+    }
+}
+
+#[test]
+#[should_panic = "get_range: range 10..201 out of bounds (size=200)"]
+fn get_range_out_of_bounds() {
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&random_ints::<u8>(200));
+    base4_integer.get_range::<u8>(10..201);
+}
+
+#[test]
+fn count_and_histogram() {
+    let ints = random_ints::<u8>(150);
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints);
+
+    let mut expected = [0; 4];
+    ints.iter().for_each(|digit| expected[*digit as usize] += 1);
+
+    assert!(base4_integer.histogram() == expected);
+    (0..4_u8).for_each(|digit| assert!(base4_integer.count(digit) == expected[digit as usize]));
+    assert!(base4_integer.count(4_u64) == 0);
+
+    assert!(base4_integer[2].histogram().iter().sum::<usize>() == 22);
+    assert!(Base4Int::new().histogram() == [0; 4]);
+}
+
+#[test]
+fn bitwise_digit_ops() {
+    let (a_ints, b_ints) = (random_ints::<u8>(64), random_ints::<u8>(64));
+    let (mut a, mut b) = (Base4::new(), Base4::new());
+    a.push_all(&a_ints);
+    b.push_all(&b_ints);
+
+    let zipped = |f: fn(u8, u8) -> u8| {
+        a_ints
+            .iter()
+            .zip(b_ints.iter())
+            .map(|(x, y)| f(*x, *y))
+            .collect::<Vec<_>>()
+    };
+
+    assert!(a.and(&b).peek_all::<u8>() == zipped(|x, y| x & y));
+    assert!(a.or(&b).peek_all::<u8>() == zipped(|x, y| x | y));
+    assert!(a.xor(&b).peek_all::<u8>() == zipped(|x, y| x ^ y));
+    assert!(a.xor(&a).count(0_u8) == 64);
+}
+
+#[test]
+#[should_panic = "xor: length mismatch (2 != 3)"]
+fn bitwise_digit_ops_mismatch() {
+    Base4::from_u128(0, 2).xor(&Base4::from_u128(0, 3));
+}
+
+#[test]
+fn with_capacity_and_reserve() {
+    let ints = random_ints::<u8>(1000);
+    let mut base4_integer = Base4Int::with_capacity(1000);
+    base4_integer.push_all(&ints);
+    assert!(base4_integer.peek_all::<u8>() == ints);
+
+    base4_integer.reserve(100);
+    base4_integer.push_all(&ints[..100]);
+    assert!(base4_integer.total_len() == 1100);
+    assert!(base4_integer.peek_at::<u8>(1099) == ints[99]);
+
+    assert!(Base4Int::with_capacity(0).is_empty());
+}
+
+#[test]
+fn shrink_to_fit_releases_blocks() {
+    let ints = random_ints::<u8>(6400);
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints);
+    assert!(base4_integer.capacity() >= 100);
+
+    let _ = base4_integer.pop_all::<u8>();
+    base4_integer.push_all(&ints[..130]);
+    base4_integer.shrink_to_fit();
+
+    assert!(base4_integer.capacity() >= 3);
+    assert!(base4_integer.capacity() < 100);
+    assert!(base4_integer.peek_all::<u8>() == ints[..130]);
+}
+
+#[test]
+fn base4_push_all_no_side_effects() {
+    let mut codec = Base4::new();
+    assert!(codec.push_all(&[0_u8, 1]));
+
+    assert!(!codec.push_all(&[2_u8, 4, 3]));
+    assert!(codec.peek_all::<u8>() == vec![0, 1]);
+
+    assert!(codec.push_all(&[2_u8, 3]));
+    assert!(codec.peek_all::<u8>() == vec![0, 1, 2, 3]);
+}
+
+#[test]
+fn base4_push_all_counts_used_capacity() {
+    let (first, second) = (random_ints::<u8>(40), random_ints::<u8>(40));
+    let mut codec = Base4::new();
+
+    assert!(codec.push_all(&first));
+    assert!(!codec.push_all(&second));
+    assert!(codec.len() == 40);
+    assert!(codec.peek_all::<u8>() == first);
+
+    assert!(codec.push_all(&second[..24]));
+    assert!(codec.len() == 64);
+}
+
+#[test]
+fn pop_front_is_fifo() {
+    let ints = random_ints::<u8>(130);
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints);
+
+    for (popped, digit) in ints.iter().enumerate() {
+        assert!(base4_integer.pop_front() == Some(*digit));
+        assert!(base4_integer.total_blocks() == (129 - popped).div_ceil(64));
+        if popped == 65 {
+            assert!(base4_integer[0].len() == 64);
+            assert!(base4_integer.peek_all::<u8>() == ints[66..]);
+        }
+    }
+
+    assert!(base4_integer.pop_front().is_none());
+}
+
+#[test]
+fn drain_across_blocks() {
+    let ints = random_ints::<u8>(200);
+    let mut original = Base4Int::new();
+    original.push_all(&ints);
+
+    for range in [0..0, 10..20, 60..70, 30..150, 100..200, 0..200] {
+        let mut base4_integer = original.clone();
+        let drained = base4_integer.drain(range.clone()).collect::<Vec<_>>();
+
+        let mut expected = ints.clone();
+        assert!(drained == expected.drain(range).collect::<Vec<_>>());
+        assert!(base4_integer.peek_all::<u8>() == expected);
+        assert!(base4_integer.total_blocks() == expected.len().div_ceil(64));
+    }
+
+    let mut base4_integer = original.clone();
+    let mut drain = base4_integer.drain(50..150);
+    assert!(drain.len() == 100);
+    assert!(drain.next() == Some(ints[50]));
+    drop(drain);
+    assert!(base4_integer.peek_all::<u8>() == [&ints[..50], &ints[150..]].concat());
+}
+
+#[test]
+fn retain_repacks_survivors() {
+    let mut ints = random_ints::<u8>(300);
+    ints[7] = 3;
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints);
+
+    base4_integer.retain(|digit| digit == 3);
+    let kept = ints.iter().filter(|digit| **digit == 3).count();
+
+    assert!(base4_integer.total_len() == kept);
+    assert!(base4_integer.total_blocks() == kept.div_ceil(64));
+    assert!(base4_integer.peek_all::<u8>() == vec![3; kept]);
+
+    base4_integer.retain(|_| false);
+    assert!(base4_integer.is_empty());
+}
+
+#[test]
+fn map_digits_preserves_layout() {
+    let ints = random_ints::<u8>(150);
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints);
+
+    let mapped = base4_integer.map_digits(|digit| (digit + 1) % 4);
+    assert!(mapped.total_blocks() == 3);
+    assert!(mapped[2].len() == 22);
+    assert!(mapped.peek_all::<u8>() == ints.iter().map(|d| (d + 1) % 4).collect::<Vec<_>>());
+
+    let mut rc = base4_integer.map_digits(|digit| 3 - digit);
+    rc.reverse();
+    base4_integer.rc_inplace();
+    assert!(rc == base4_integer);
+}
+
+#[test]
+#[should_panic = "Base4Int only accepts value bounded within 0..=3"]
+fn map_digits_out_of_range() {
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&[0_u8, 1]);
+    base4_integer.map_digits(|digit| digit + 3);
+}
+
+#[test]
+fn windows_span_blocks() {
+    let ints = random_ints::<u8>(150);
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints);
+
+    for n in [1, 5, 63, 64] {
+        let windows = base4_integer.windows(n);
+        assert!(windows.len() == 151 - n);
+
+        for (window, expected) in windows.zip(ints.windows(n)) {
+            assert!(window.peek_all::<u8>() == expected);
+        }
+    }
+
+    let mut short = Base4Int::new();
+    short.push_all(&ints[..10]);
+    assert!(short.windows(11).next().is_none());
+    assert!(short.windows(10).count() == 1);
+}
+
+#[test]
+#[should_panic = "window length 65 out of range 1..=64"]
+fn windows_too_long() {
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&random_ints::<u8>(10));
+    base4_integer.windows(65);
+}
+
+#[test]
+#[should_panic = "window length 0 out of range 1..=64"]
+fn windows_empty_length() {
+    Base4Int::new().windows(0);
+}
+
+#[test]
+fn blocks_iterate_in_order() {
+    let ints = random_ints::<u8>(150);
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints);
+
+    let digits = base4_integer
+        .blocks()
+        .flat_map(|block| block.iter())
+        .collect::<Vec<_>>();
+
+    assert!(digits == ints);
+    assert!(base4_integer.blocks().count() == base4_integer.total_blocks());
+    assert!(Base4Int::new().blocks().next().is_none());
+}
+
+#[test]
+fn from_blocks_and_into_blocks() {
+    let ints = random_ints::<u8>(150);
+    let blocks = ints
+        .chunks(64)
+        .map(|chunk| {
+            let mut codec = Base4::new();
+            codec.push_all(chunk);
+            codec
+        })
+        .collect::<Vec<_>>();
+
+    let base4_integer = Base4Int::from_blocks(blocks.clone()).unwrap();
+    assert!(base4_integer.peek_all::<u8>() == ints);
+    assert!(base4_integer.into_blocks() == blocks);
+
+    let mut with_empty = blocks.clone();
+    with_empty.push(Base4::new());
+    assert!(Base4Int::from_blocks(with_empty).unwrap().total_len() == 150);
+
+    let mut swapped = blocks.clone();
+    swapped.swap(1, 2);
+    assert!(
+        Base4Int::from_blocks(swapped).err()
+            == Some(Base4Error::PartialBlock { index: 1, size: 22 })
+    );
+
+    assert!(Base4Int::from_blocks(vec![]).unwrap().is_empty());
+    assert!(
+        Base4Int::from_blocks(vec![Base4::new()])
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[test]
+fn push_front_across_blocks() {
+    let mut ints = random_ints::<u8>(127);
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints);
+
+    for digit in [3_u8, 0, 1, 2] {
+        base4_integer.push_front(digit);
+        ints.insert(0, digit);
+
+        assert!(base4_integer.peek_all::<u8>() == ints);
+        assert!(base4_integer.total_blocks() == ints.len().div_ceil(64));
+        (0..ints.len()).for_each(|i| assert!(base4_integer.peek_at::<u8>(i) == ints[i]));
+    }
+
+    let mut empty = Base4Int::new();
+    empty.push_front(2_u8);
+    assert!(empty.peek_all::<u8>() == vec![2]);
+}
+
+#[test]
+fn radix_strings() {
+    let ints = random_ints::<usize>(64);
+    let mut codec = Base4::new();
+    codec.push_all(&ints.iter().map(|i| *i as u64).collect::<Vec<_>>());
+
+    let value = base4_encode(&ints);
+    assert!(codec.to_radix_string(10) == value.to_string());
+    assert!(codec.to_radix_string(2) == format!("{:b}", value));
+    assert!(codec.to_radix_string(8) == format!("{:o}", value));
+    assert!(codec.to_hex() == format!("{:x}", value));
+    assert!(u128::from_str_radix(&codec.to_radix_string(36), 36).unwrap() == value);
+
+    assert!(Base4::new().to_radix_string(7) == "0");
+    assert!(Base4::from_u128(1, 3).to_hex() == "1");
+}
+
+#[test]
+fn bit_and_byte_sizes() {
+    for len in [0, 1, 4, 5, 64, 65, 130] {
+        let mut base4_integer = Base4Int::new();
+        base4_integer.push_all(&random_ints::<u8>(len));
+
+        assert!(base4_integer.num_bits() == len * 2);
+        assert!(base4_integer.byte_len() == len.div_ceil(4));
+        assert!(base4_integer.to_bytes().len() == 8 + base4_integer.byte_len());
+
+        let block_bytes = base4_integer.blocks().map(Base4::byte_len).sum::<usize>();
+        assert!(block_bytes == base4_integer.byte_len());
+    }
+}
+
+#[test]
+fn base4_raw_accessors() {
+    let ints = random_ints::<usize>(100);
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints.iter().map(|i| *i as u8).collect::<Vec<_>>());
+
+    let mut offset = 0;
+    for block in base4_integer.blocks() {
+        assert!(block.as_u128() == base4_encode(&ints[offset..offset + block.len()]));
+        offset += block.len();
+    }
+    assert!(offset == 100);
+}
+
+#[test]
+fn repeat_digit() {
+    for digit in 0..4_u8 {
+        let base4_integer = Base4Int::repeat(digit, 200);
+        assert!(base4_integer.peek_all::<u8>() == vec![digit; 200]);
+        assert!(base4_integer.total_blocks() == 4);
+    }
+
+    assert!(Base4Int::repeat(1_u64, 0).is_empty());
+}
+
+#[test]
+#[should_panic = "Base4Int only accepts value bounded within 0..=3, got 5"]
+fn repeat_out_of_range() {
+    Base4Int::repeat(5_u8, 10);
+}
+
+#[test]
+fn from_pattern_tiles() {
+    let pattern = random_ints::<u8>(5);
+    for total_len in [0, 3, 5, 64, 131] {
+        let base4_integer = Base4Int::from_pattern(&pattern, total_len).unwrap();
+        let expected = pattern
+            .iter()
+            .copied()
+            .cycle()
+            .take(total_len)
+            .collect::<Vec<_>>();
+
+        assert!(base4_integer.peek_all::<u8>() == expected);
+    }
+
+    assert!(Base4Int::from_pattern(&[], 0).unwrap().is_empty());
+    assert!(Base4Int::from_pattern(&[], 3).err() == Some(Base4Error::EmptyPattern));
+    assert!(Base4Int::from_pattern(&[1, 9], 0).err() == Some(Base4Error::OutOfRange { value: 9 }));
+}
+
+#[test]
+fn debug_shows_digits() {
+    let ints = random_ints::<u8>(200);
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints);
+
+    let shown = ints[..128]
+        .iter()
+        .map(|d| (b'0' + d) as char)
+        .collect::<String>();
+    let debug = format!("{:?}", base4_integer);
+
+    assert!(debug == format!("Base4Int {{ len: 200, blocks: 4, digits: \"{shown}…\" }}"));
+    assert!(
+        format!("{:?}", base4_integer.debug_raw())
+            .matches("Base4 {")
+            .count()
+            == 4
+    );
+    assert!(format!("{:?}", Base4Int::new()) == "Base4Int { len: 0, blocks: 0, digits: \"\" }");
+}
+
+#[test]
+fn bulk_decode_matches_peek_at() {
+    for len in [0, 1, 3, 4, 5, 63, 64, 65, 127, 128, 1000] {
+        let ints = random_ints::<u8>(len);
+        let mut base4_integer = Base4Int::new();
+        base4_integer.push_all(&ints);
+
+        assert!(base4_integer.peek_all::<u8>() == ints);
+        assert!(
+            base4_integer.peek_all::<u64>()
+                == (0..len)
+                    .map(|i| base4_integer.peek_at::<u64>(i))
+                    .collect::<Vec<_>>()
+        );
+
+        for block in base4_integer.blocks() {
+            assert!(block.peek_all::<u8>() == block.iter().collect::<Vec<_>>());
+        }
+    }
+}
+
+#[test]
+fn base4_pop_all_single_pass() {
+    for len in [0, 1, 5, 63, 64] {
+        let ints = random_ints::<u32>(len);
+        let mut codec = Base4::new();
+        codec.push_all(&ints);
+
+        assert!(codec.pop_all::<u32>() == ints);
+        assert!(codec.is_empty());
+        assert!(codec.as_u128() == 0);
+        assert!(codec.push(2_u8));
+    }
+}
+
+#[test]
+fn base4_int_pop_all_many_blocks() {
+    let ints = random_ints::<u16>(64 * 50 + 17);
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints);
+
+    assert!(base4_integer.pop_all::<u16>() == ints);
+    assert!(base4_integer.is_empty());
+    assert!(base4_integer.pop_all::<u8>().is_empty());
+
+    base4_integer.push_all(&ints[..3]);
+    assert!(base4_integer.pop_all::<u16>() == ints[..3]);
+}
+
+#[test]
+fn peek_all_into_reuses_buffer() {
+    let mut buf = Vec::<u8>::new();
+    for len in [200, 10, 0, 130] {
+        let ints = random_ints::<u8>(len);
+        let mut base4_integer = Base4Int::new();
+        base4_integer.push_all(&ints);
+
+        base4_integer.peek_all_into(&mut buf);
+        assert!(buf == ints);
+        assert!(buf.capacity() >= 200);
+    }
+}
+
+#[test]
+fn base4_try_push_errors() {
+    let mut codec = Base4::new();
+    assert!(codec.try_push(9_u64) == Err(Base4PushError::OutOfRange { value: 9 }));
+    assert!(codec.is_empty());
+
+    for digit in random_ints::<u8>(64) {
+        assert!(codec.try_push(digit).is_ok());
+    }
+
+    assert!(codec.try_push(0_u8) == Err(Base4PushError::Full));
+    assert!(codec.try_push(4_u8) == Err(Base4PushError::OutOfRange { value: 4 }));
+    assert!(!codec.push(0_u8));
+    assert!(codec.len() == 64);
+    assert!(Base4PushError::Full.to_string() == "Base4 block is full, it holds 64 digits");
+}
+
+#[test]
+fn find_across_blocks() {
+    let ints = random_ints::<u8>(300);
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints);
+
+    let naive = |pattern: &[u8]| ints.windows(pattern.len()).position(|w| w == pattern);
+
+    for range in [0..1, 60..70, 62..66, 63..127, 100..200, 250..300, 0..300] {
+        let pattern = &ints[range];
+        assert!(base4_integer.find(pattern) == naive(pattern));
+        assert!(base4_integer.contains(pattern));
+    }
+
+    let mut missing = ints[120..200].to_vec();
+    missing[79] = (missing[79] + 1) % 4;
+    assert!(base4_integer.find(&missing) == naive(&missing));
+
+    assert!(base4_integer.find(&[]) == Some(0));
+    assert!(base4_integer.find(&[0; 301]).is_none());
+    assert!(!Base4Int::new().contains(&[0]));
+
+    let mut repetitive = Base4Int::repeat(0_u8, 1000);
+    let mut pattern = vec![0_u8; 200];
+    pattern.push(1);
+    assert!(repetitive.find(&pattern).is_none());
+
+    repetitive.push(1_u8);
+    assert!(repetitive.find(&pattern) == Some(800));
+}
+
+#[test]
+#[should_panic = "Base4Int only accepts value bounded within 0..=3"]
+fn find_out_of_range() {
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&[0_u8, 1]);
+    base4_integer.find(&[0, 4]);
+}
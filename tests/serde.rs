@@ -0,0 +1,28 @@
+//! Kept apart from `mod.rs`, since linking `serde_json` brings in
+//! extra `PartialEq` impls that break type inference over there.
+#![cfg(feature = "serde")]
+
+use base4::{Base4, Base4Int};
+
+#[test]
+fn serde_round_trip() {
+    let ints = (0..150).map(|i| (i * 7 % 4) as u8).collect::<Vec<_>>();
+    let mut base4_integer = Base4Int::new();
+    base4_integer.push_all(&ints);
+
+    let json = serde_json::to_string(&base4_integer).unwrap();
+    let decoded: Base4Int = serde_json::from_str(&json).unwrap();
+
+    assert!(decoded.total_blocks() == 3);
+    assert!(decoded[2].len() == 22);
+    assert!(decoded.peek_all::<u8>() == ints);
+
+    let json = serde_json::to_string(&decoded[1]).unwrap();
+    let codec: Base4 = serde_json::from_str(&json).unwrap();
+    assert!(codec.peek_all::<u8>() == ints[64..128]);
+
+    assert!(serde_json::from_str::<Base4>("[65,0]").is_err());
+    assert!(serde_json::from_str::<Base4>("[1,4]").is_err());
+    assert!(serde_json::from_str::<Base4Int>("[[2,1],[1,1]]").is_err());
+    assert!(serde_json::from_str::<Base4Int>("[[0,0]]").is_err());
+}